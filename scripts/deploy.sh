@@ -8,6 +8,11 @@ set -euo pipefail
 
 ROOT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")/.." && pwd)"
 SERVICE_PORT="${SERVICE_PORT:-8080}"
+# DEMO_MODE=true trusts wallet addresses sent by the web client on the shared hub chain and
+# accepts tickets without metadata blobs. Off by default: owners come from block signers
+DEMO_MODE="${DEMO_MODE:-false}"
+# MIGRATION_ADMIN=<owner> allows that signer to import state exported from an older deployment
+MIGRATION_ADMIN="${MIGRATION_ADMIN:-}"
 FAUCET_URL="https://faucet.testnet-conway.linera.net"
 WALLET_DIR="$HOME/.config/linera"
 
@@ -119,7 +124,10 @@ RETRY_COUNT=0
 APP_ID=""
 
 # Prepare JSON parameters (marketplace_chain = this hub chain)
-JSON_PARAMS="{\"marketplace_chain\":\"$CHAIN_ID\"}"
+JSON_PARAMS="{\"marketplace_chain\":\"$CHAIN_ID\",\"demo_mode\":$DEMO_MODE}"
+if [ -n "$MIGRATION_ADMIN" ]; then
+    JSON_PARAMS="${JSON_PARAMS%\}},\"migration_admin\":\"$MIGRATION_ADMIN\"}"
+fi
 echo "   Parameters: $JSON_PARAMS"
 
 while [ $RETRY_COUNT -lt $MAX_RETRIES ] && [ -z "$APP_ID" ]; do
@@ -184,6 +192,8 @@ VITE_LINERA_FAUCET_URL=https://faucet.testnet-conway.linera.net
 # Deployed: $(date -u +"%Y-%m-%dT%H:%M:%SZ")
 VITE_LINERA_APPLICATION_ID=$APP_ID
 VITE_MARKETPLACE_CHAIN_ID=$CHAIN_ID
+# Send owner addresses with mutations - only honored when deployed with DEMO_MODE=true
+VITE_DEMO_MODE=$DEMO_MODE
 # Hub node service URL — set to your linera-service endpoint for marketplace reads.
 # Start with: linera service --port 8080
 # Then set: VITE_HUB_NODE_URL=http://localhost:8080
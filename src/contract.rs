@@ -13,15 +13,23 @@
 
 mod state;
 
+use std::collections::BTreeSet;
+
 use linera_sdk::{
-    linera_base_types::{ChainId, DataBlobHash, StreamUpdate, WithContractAbi},
-    views::{RootView, View},
+    linera_base_types::{AccountOwner, ChainId, DataBlobHash, StreamUpdate, WithContractAbi},
+    views::{LogView, MapView, RootView, View},
     Contract, ContractRuntime,
 };
+use serde::{de::DeserializeOwned, Serialize};
 use ticketing::{
-    Event, EventId, ApplicationParameters, Listing, ListingStatus, 
-    Message, Operation, StreamEvent, Ticket, TicketId, TicketingAbi, 
-    MAX_BPS, MARKETPLACE_STREAM,
+    migration::{
+        self, LegacyBalance, LegacyEvent, LegacyListing, LegacyTicket, LegacyTicketHistory,
+    },
+    Allowlist, CheckIn, CollectibleTerms, Event, EventId, EventRole, ApplicationParameters, FraudAlert,
+    HolderKey, Listing, ListingStatus, MerkleHash, Message, Operation, PendingWrite, Presale,
+    Redemption, SequencedEvent, StreamEvent, SyncCollection, SyncCursor, SyncPager, SyncProgress,
+    SyncRecords, Ticket, TicketApproval, TicketId, TicketMetadata, TicketStatus, TicketingAbi,
+    TransferPolicy, WriteStatus, MAX_BPS, MAX_MINT_BATCH, MARKETPLACE_STREAM,
 };
 
 use self::state::TicketingState;
@@ -41,7 +49,7 @@ impl Contract for TicketingContract {
     type Message = Message;
     type InstantiationArgument = ();
     type Parameters = ApplicationParameters;
-    type EventValue = StreamEvent;
+    type EventValue = SequencedEvent;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
         let state = TicketingState::load(runtime.root_view_storage_context())
@@ -54,31 +62,18 @@ impl Contract for TicketingContract {
         // Get marketplace chain from application parameters (shared across all chains)
         // If empty, use the creation chain (current chain during instantiation)
         let params = self.runtime.application_parameters();
-        let marketplace_chain = if params.marketplace_chain.is_empty() {
-            self.runtime.chain_id().to_string()
-        } else {
-            params.marketplace_chain.clone()
-        };
-        self.state.marketplace_chain.set(marketplace_chain.clone());
+        let marketplace_chain = params
+            .marketplace_chain
+            .unwrap_or_else(|| self.runtime.chain_id());
+        self.state.marketplace_chain.set(Some(marketplace_chain));
         self.state.total_royalties.set(0);
         
         eprintln!("[INSTANTIATE] Marketplace chain set to: {}", marketplace_chain);
     }
 
     async fn execute_operation(&mut self, operation: Operation) -> Self::Response {
-        let caller_chain = self.runtime.chain_id().to_string();
-        // Read marketplace_chain from state (set during instantiation) or fallback to params
-        let params = self.runtime.application_parameters();
-        let marketplace_chain = if params.marketplace_chain.is_empty() {
-            // Use stored state if params are empty
-            self.state.marketplace_chain.get().clone()
-        } else {
-            params.marketplace_chain.clone()
-        };
-        
-        // Validate marketplace chain is set
-        assert!(!marketplace_chain.is_empty(), "Invalid marketplace chain ID: {}", marketplace_chain);
-        
+        let caller_chain = self.runtime.chain_id();
+        let marketplace_chain = self.hub_chain();
         let is_hub = caller_chain == marketplace_chain;
         eprintln!("[EXECUTE] caller={}, hub={}, is_hub={}", caller_chain, marketplace_chain, is_hub);
 
@@ -94,10 +89,19 @@ impl Contract for TicketingContract {
                 image_url,
                 end_time,
                 base_price,
+                sale_start,
+                sale_end,
+                resale_start,
+                resale_end,
+                max_per_holder,
+                max_purchases_per_holder,
+                transfer_lockout_secs,
+                transfer_policy,
             } => {
+                let event_id = self.assign_event_id(&caller_chain, event_id).await;
                 let event = Event {
-                    id: event_id.clone(),
-                    organizer_chain: caller_chain.clone(),
+                    id: event_id,
+                    organizer_chain: caller_chain,
                     name,
                     description,
                     venue,
@@ -105,19 +109,30 @@ impl Contract for TicketingContract {
                     royalty_bps,
                     max_tickets,
                     minted_tickets: 0,
+                    released_tickets: 0,
                     image_url,
                     end_time,
                     base_price,
+                    sale_start,
+                    sale_end,
+                    resale_start,
+                    resale_end,
+                    max_per_holder,
+                    max_purchases_per_holder,
+                    transfer_lockout_secs,
+                    transfer_policy,
+                    collectible: None,
                 };
 
                 if is_hub {
                     // We're on the hub - create directly
-                    self.create_event_local(event).await;
+                    assert!(self.create_event_local(event).await, "event already exists");
                 } else {
                     // Store locally for optimistic display (user sees immediately)
-                    self.create_event_local(event.clone()).await;
+                    assert!(self.create_event_local(event.clone()).await, "event already exists");
+                    self.track_write(PendingWrite::Event(event.id.clone()));
                     // Also forward to hub for authoritative storage
-                    self.forward_to_hub(Message::CreateEventOnHub { event });
+                    self.forward_to_hub_tracked(Message::CreateEventOnHub { event });
                 }
             }
             
@@ -127,111 +142,128 @@ impl Contract for TicketingContract {
                 blob_hash,
                 owner,
                 image_url,
+                transfer_policy,
+                presale_proof,
             } => {
+                let owner = self.caller_owner(owner);
+                let owner_signed = self.runtime.authenticated_signer() == Some(owner);
                 // Minting happens on the hub (where events live)
                 if is_hub {
-                    self.mint_ticket(caller_chain, owner, event_id, seat, blob_hash, image_url).await;
-                } else {
-                    // Forward to hub for authoritative processing
-                    self.forward_to_hub(Message::MintTicketRequest {
-                        minter_chain: caller_chain.clone(),
-                        owner: owner.clone(),
-                        event_id: event_id.clone(),
-                        seat: seat.clone(),
+                    self.mint_ticket(
+                        caller_chain,
+                        owner,
+                        owner_signed,
+                        event_id,
+                        seat,
                         blob_hash,
-                        image_url: image_url.clone(),
-                    });
+                        image_url,
+                        transfer_policy,
+                        presale_proof,
+                    )
+                    .await;
+                } else {
+                    // Reject seats we already know are taken before bothering the hub
+                    assert!(
+                        !self.is_seat_taken(&event_id, &seat).await,
+                        "seat already minted"
+                    );
 
                     // Optimistic local mint: store ticket locally for immediate display
                     // (mirrors CreateEvent pattern: local storage + hub forwarding)
-                    if let Some(event) = self.state.events.get(&event_id).await.unwrap() {
-                        if event.organizer_chain == caller_chain
-                            && event.minted_tickets < event.max_tickets
-                        {
-                            if let Ok(hub_chain_id) = marketplace_chain.parse::<ChainId>() {
-                                let ticket_id = Ticket::create_ticket_id(
-                                    &hub_chain_id,
-                                    &self.runtime.application_id().forget_abi(),
-                                    &event_id,
-                                    &seat,
-                                    &caller_chain,
-                                    &blob_hash,
-                                    event.minted_tickets,
-                                )
-                                .expect("failed to create ticket id");
-
-                                let minted_at = self.runtime.system_time().micros() / 1000;
-                                let ticket = Ticket {
-                                    ticket_id: ticket_id.clone(),
-                                    event_id: event_id.clone(),
-                                    event_name: event.name.clone(),
-                                    seat: seat.clone(),
-                                    organizer_chain: event.organizer_chain.clone(),
-                                    owner_chain: caller_chain.clone(),
-                                    owner: owner.clone(),
-                                    minter_chain: caller_chain.clone(),
-                                    royalty_bps: event.royalty_bps,
-                                    metadata_hash: blob_hash,
-                                    last_sale_price: None,
-                                    image_url: image_url.clone(),
-                                    minted_at,
-                                };
-
-                                // Store ticket locally
-                                self.state.tickets.insert(&ticket_id, ticket).unwrap();
-
-                                // Track ownership locally
-                                let mut owned = self
-                                    .state
-                                    .owned_ticket_ids
-                                    .get(&caller_chain)
-                                    .await
-                                    .unwrap()
-                                    .unwrap_or_default();
-                                owned.insert(ticket_id.clone());
-                                self.state
-                                    .owned_ticket_ids
-                                    .insert(&caller_chain, owned)
-                                    .unwrap();
-
-                                // Update event counter locally
-                                let mut updated_event = event;
-                                updated_event.minted_tickets += 1;
-                                self.state
-                                    .events
-                                    .insert(&event_id, updated_event)
-                                    .unwrap();
-
-                                // Create initial history record
-                                use ticketing::{
-                                    AcquisitionType, OwnershipRecord, TicketHistory,
-                                };
-                                let history = TicketHistory {
-                                    ownership_history: vec![OwnershipRecord {
-                                        owner: owner.clone(),
-                                        owner_chain: caller_chain.clone(),
-                                        acquired_at: minted_at,
-                                        price_paid: None,
-                                        acquisition_type: AcquisitionType::Minted,
-                                    }],
-                                    price_history: vec![],
-                                };
-                                self.state
-                                    .ticket_history
-                                    .insert(&ticket_id, history)
-                                    .unwrap();
-
-                                eprintln!(
-                                    "[LOCAL_MINT] Optimistic ticket stored for event '{}'",
-                                    event_id.value
-                                );
-                            }
-                        }
-                    }
+                    let pending_ticket_id = self
+                        .mint_optimistically(
+                            caller_chain,
+                            owner,
+                            &event_id,
+                            std::slice::from_ref(&seat),
+                            blob_hash,
+                            &image_url,
+                            transfer_policy,
+                        )
+                        .await
+                        .pop();
+
+                    // Forward to hub for authoritative processing
+                    self.forward_to_hub_tracked(Message::MintTicketRequest {
+                        minter_chain: caller_chain,
+                        owner,
+                        event_id,
+                        seat,
+                        blob_hash,
+                        image_url,
+                        transfer_policy,
+                        presale_proof,
+                        owner_signed,
+                        pending_ticket_id,
+                    });
                     eprintln!("[FORWARD] MintTicket forwarded to hub");
                 }
             }
             
+            Operation::MintTicketBatch {
+                event_id,
+                seats,
+                ranges,
+                blob_hash,
+                owner,
+                image_url,
+                transfer_policy,
+                presale_proof,
+            } => {
+                let owner = self.caller_owner(owner);
+                let owner_signed = self.runtime.authenticated_signer() == Some(owner);
+                if is_hub {
+                    let seats = ticketing::expand_seats(&seats, &ranges);
+                    self.mint_ticket_batch(
+                        caller_chain,
+                        owner,
+                        owner_signed,
+                        event_id,
+                        seats,
+                        blob_hash,
+                        image_url,
+                        transfer_policy,
+                        presale_proof,
+                    )
+                    .await;
+                } else {
+                    // Reject seats we already know are taken before bothering the hub
+                    let expanded = ticketing::expand_seats(&seats, &ranges);
+                    for seat in &expanded {
+                        assert!(
+                            !self.is_seat_taken(&event_id, seat).await,
+                            "seat already minted"
+                        );
+                    }
+                    let pending_ticket_ids = self
+                        .mint_optimistically(
+                            caller_chain,
+                            owner,
+                            &event_id,
+                            &expanded,
+                            blob_hash,
+                            &image_url,
+                            transfer_policy,
+                        )
+                        .await;
+                    // Ranges are expanded on the hub to keep the message small
+                    self.forward_to_hub_tracked(Message::MintTicketBatchRequest {
+                        minter_chain: caller_chain,
+                        owner,
+                        event_id,
+                        seats,
+                        ranges,
+                        blob_hash,
+                        image_url,
+                        transfer_policy,
+                        presale_proof,
+                        owner_signed,
+                        pending_ticket_ids,
+                    });
+                    eprintln!("[FORWARD] MintTicketBatch forwarded to hub");
+                }
+            }
+            
             Operation::TransferTicket {
                 ticket_id,
                 buyer_chain,
@@ -239,10 +271,18 @@ impl Contract for TicketingContract {
                 sale_price,
             } => {
                 let ticket = self.get_ticket(&ticket_id).await;
-                // Ownership check: must match both chain and owner
-                assert_eq!(ticket.owner_chain, caller_chain, "Not the ticket owner chain");
-                // Note: In demo mode, tickets should be transferred with owner tracking
-                self.transfer(ticket, caller_chain, buyer_chain, new_owner, sale_price).await;
+                if self.is_demo_mode() {
+                    // Demo mode has no owner to check against beyond the chain
+                    assert_eq!(ticket.owner_chain, caller_chain, "Not the ticket owner chain");
+                } else {
+                    let spender = self.caller_owner(None);
+                    // Other chains, the hub included, only hold synced copies that may be stale,
+                    // so owners and operators act from the owner chain
+                    assert_eq!(ticket.owner_chain, caller_chain, "Not the ticket owner chain");
+                    self.assert_owner_or_operator(&ticket, caller_chain, &spender).await;
+                }
+                let seller_chain = ticket.owner_chain;
+                self.transfer(ticket, seller_chain, buyer_chain, new_owner, sale_price, false).await;
             }
             
             Operation::ClaimTicket {
@@ -250,44 +290,71 @@ impl Contract for TicketingContract {
                 ticket_id,
                 new_owner,
                 sale_price,
+                owner,
             } => {
+                let claimant = self.caller_owner(owner);
                 if source_chain == caller_chain {
                     // Local claim - no-op
                 } else {
-                    self.remote_claim(source_chain, ticket_id, caller_chain, new_owner, sale_price);
+                    // Fail fast on a known frozen ticket (the source chain re-checks)
+                    if let Some(ticket) = self.state.tickets.get(&ticket_id).await.unwrap() {
+                        self.assert_transferable(&ticket).await;
+                        assert!(
+                            ticket.transfer_policy.allows_transfer(false),
+                            "ticket can only change hands through the marketplace or not at all"
+                        );
+                    }
+                    self.remote_claim(
+                        source_chain,
+                        ticket_id,
+                        caller_chain,
+                        claimant,
+                        new_owner,
+                        sale_price,
+                    );
                 }
             }
             
             Operation::CreateListing { ticket_id, price, seller } => {
+                let seller = self.caller_owner(seller);
                 if is_hub {
                     // On hub - create directly
                     self.create_listing_local(caller_chain, seller, ticket_id, price).await;
                 } else {
                     // Must have ticket locally, then forward to hub
                     let ticket = self.get_ticket(&ticket_id).await;
-                    assert_eq!(ticket.owner_chain, caller_chain, "Not the ticket owner chain");
-                    // Case-insensitive comparison for wallet addresses
-                    assert_eq!(ticket.owner.to_lowercase(), seller.to_lowercase(), "Not the ticket owner");
+                    self.assert_owner_or_operator(&ticket, caller_chain, &seller).await;
+                    self.assert_transferable(&ticket).await;
+                    assert!(ticket.transfer_policy.allows_listing(), "soulbound tickets cannot be listed");
+                    self.assert_resale_open(&ticket).await;
                     
+                    // Operators list on behalf of the owner, who receives the proceeds
                     let listing = Listing {
                         ticket_id: ticket_id.clone(),
-                        seller_chain: caller_chain.clone(),
-                        seller: seller.clone(),
+                        seller_chain: ticket.owner_chain,
+                        seller: ticket.owner,
                         price,
                         status: ListingStatus::Active,
                     };
                     
                     // Store listing locally for optimistic display
-                    self.state.listings.insert(&ticket_id, listing.clone()).unwrap();
+                    self.state.listings.insert(&ticket_id, listing).unwrap();
+                    self.track_write(PendingWrite::Listing(ticket_id.clone()));
                     
                     // Forward to hub for authoritative storage
-                    self.forward_to_hub(Message::CreateListingOnHub { listing });
+                    self.forward_to_hub_tracked(Message::CreateListingOnHub {
+                        ticket_id,
+                        seller_chain: caller_chain,
+                        seller,
+                        price,
+                    });
                 }
             }
             
             Operation::CancelListing { ticket_id, seller } => {
+                let seller = self.caller_owner(seller);
                 if is_hub {
-                    self.cancel_listing_local(caller_chain.clone(), seller, ticket_id).await;
+                    self.cancel_listing_local(caller_chain, seller, ticket_id).await;
                 } else {
                     self.forward_to_hub(Message::CancelListingOnHub {
                         ticket_id,
@@ -298,6 +365,7 @@ impl Contract for TicketingContract {
             }
             
             Operation::BuyListing { ticket_id, price, buyer } => {
+                let buyer = self.caller_owner(buyer);
                 if is_hub {
                     self.buy_listing_local(caller_chain, buyer, ticket_id, price).await;
                 } else {
@@ -310,32 +378,226 @@ impl Contract for TicketingContract {
                 }
             }
             
+            Operation::SetHolderLimits { event_id, max_per_holder, max_purchases_per_holder } => {
+                if is_hub {
+                    self.set_holder_limits(caller_chain, event_id, max_per_holder, max_purchases_per_holder).await;
+                } else {
+                    self.forward_to_hub(Message::SetHolderLimitsOnHub {
+                        event_id,
+                        requester_chain: caller_chain,
+                        max_per_holder,
+                        max_purchases_per_holder,
+                    });
+                }
+            }
+            
+            Operation::SetPresale { event_id, presale } => {
+                if is_hub {
+                    self.set_presale(caller_chain, event_id, presale).await;
+                } else {
+                    self.forward_to_hub(Message::SetPresaleOnHub {
+                        event_id,
+                        requester_chain: caller_chain,
+                        presale,
+                    });
+                }
+            }
+            
+            Operation::UpdatePresaleAllowlist { event_id, added, removed } => {
+                if is_hub {
+                    self.update_presale_allowlist(caller_chain, event_id, added, removed).await;
+                } else {
+                    self.forward_to_hub(Message::UpdatePresaleAllowlistOnHub {
+                        event_id,
+                        requester_chain: caller_chain,
+                        added,
+                        removed,
+                    });
+                }
+            }
+            
+            Operation::BurnTicket { ticket_id, owner, reason, release_seat } => {
+                let owner = self.caller_owner(owner);
+                if is_hub {
+                    self.burn_ticket(caller_chain, owner, ticket_id, reason, release_seat).await;
+                } else {
+                    self.forward_to_hub(Message::BurnTicketOnHub {
+                        ticket_id,
+                        requester_chain: caller_chain,
+                        owner,
+                        reason,
+                        release_seat,
+                    });
+                }
+            }
+            
+            Operation::Approve { ticket_id, operator, owner } => {
+                let owner = self.caller_owner(owner);
+                // Kept on this chain for local transfers and mirrored on the hub for listings
+                self.approve(caller_chain, owner, ticket_id.clone(), operator).await;
+                if !is_hub {
+                    self.forward_to_hub(Message::ApproveOnHub {
+                        ticket_id,
+                        requester_chain: caller_chain,
+                        owner,
+                        operator,
+                    });
+                }
+            }
+            
+            Operation::SetApprovalForAll { operator, approved, owner } => {
+                let owner = self.caller_owner(owner);
+                self.set_approval_for_all(caller_chain, owner, operator, approved).await;
+                if !is_hub {
+                    self.forward_to_hub(Message::SetApprovalForAllOnHub {
+                        requester_chain: caller_chain,
+                        owner,
+                        operator,
+                        approved,
+                    });
+                }
+            }
+            
+            Operation::ConvertToCollectibles { event_id, image_url, blob_hash, royalty_bps } => {
+                let terms = CollectibleTerms {
+                    image_url,
+                    blob_hash,
+                    royalty_bps,
+                    converted_at: 0,
+                };
+                if is_hub {
+                    self.convert_to_collectibles(caller_chain, event_id, terms).await;
+                } else {
+                    self.forward_to_hub(Message::ConvertToCollectiblesOnHub {
+                        event_id,
+                        requester_chain: caller_chain,
+                        terms,
+                    });
+                }
+            }
+            
+            Operation::RedeemTicket { ticket_id, gate } => {
+                if is_hub {
+                    self.redeem_ticket(caller_chain, ticket_id, gate).await;
+                } else {
+                    self.forward_to_hub(Message::RedeemTicketOnHub {
+                        ticket_id,
+                        scanner_chain: caller_chain,
+                        gate,
+                    });
+                }
+            }
+            
+            Operation::SubmitCheckIns { event_id, check_ins } => {
+                if is_hub {
+                    self.submit_check_ins(caller_chain, event_id, check_ins).await;
+                } else {
+                    self.forward_to_hub(Message::SubmitCheckInsOnHub {
+                        event_id,
+                        scanner_chain: caller_chain,
+                        check_ins,
+                    });
+                }
+            }
+            
+            Operation::SetTransferLockout { event_id, lockout_secs } => {
+                if is_hub {
+                    self.set_transfer_lockout(caller_chain, event_id, lockout_secs).await;
+                } else {
+                    self.forward_to_hub(Message::SetTransferLockoutOnHub {
+                        event_id,
+                        requester_chain: caller_chain,
+                        lockout_secs,
+                    });
+                }
+            }
+            
+            Operation::GrantEventRole { event_id, chain, role } => {
+                if is_hub {
+                    self.set_event_role(caller_chain, event_id, chain, role, true).await;
+                } else {
+                    self.forward_to_hub(Message::GrantEventRoleOnHub {
+                        event_id,
+                        granter_chain: caller_chain,
+                        chain,
+                        role,
+                    });
+                }
+            }
+            
+            Operation::RevokeEventRole { event_id, chain, role } => {
+                if is_hub {
+                    self.set_event_role(caller_chain, event_id, chain, role, false).await;
+                } else {
+                    self.forward_to_hub(Message::RevokeEventRoleOnHub {
+                        event_id,
+                        granter_chain: caller_chain,
+                        chain,
+                        role,
+                    });
+                }
+            }
+            
+            Operation::ImportLegacyState { events, tickets, listings, histories, balances } => {
+                assert!(is_hub, "legacy state can only be imported on the hub");
+                let admin = self
+                    .runtime
+                    .application_parameters()
+                    .migration_admin
+                    .expect("no migration admin configured");
+                assert_eq!(
+                    self.runtime.authenticated_signer(),
+                    Some(admin),
+                    "only the migration admin can import state"
+                );
+                self.import_legacy_state(events, tickets, listings, histories, balances).await;
+            }
+            
             Operation::SubscribeToHub => {
                 // Subscribe to the hub's marketplace stream for event sync
                 if !is_hub {
                     self.subscribe_to_hub();
                     
-                    // Request initial state sync from hub
-                    let params = self.runtime.application_parameters();
-                    if let Ok(hub_chain_id) = params.marketplace_chain.parse::<ChainId>() {
-                        self.runtime.send_message(hub_chain_id, Message::RequestSync {
-                            requester_chain: caller_chain.clone(),
-                        });
-                        eprintln!("[SUBSCRIBE] Sent RequestSync to hub");
-                    }
+                    // Copy the hub's state page by page, or only what changed since we last
+                    // caught up
+                    let caught_up_to =
+                        (*self.state.applied_sequence.get()).max(*self.state.snapshot_sequence.get());
+                    let since = (caught_up_to > 0).then_some(caught_up_to);
+                    let cursor = SyncCursor::start(SyncCollection::Events, since);
+                    self.state.sync_progress.set(SyncProgress {
+                        next: Some(cursor.clone()),
+                        ..SyncProgress::default()
+                    });
+                    self.request_sync_page(cursor);
+                    eprintln!("[SUBSCRIBE] Requested first sync page from hub");
                     
                     eprintln!("[SUBSCRIBE] User chain subscribed to hub stream");
                 } else {
                     eprintln!("[SUBSCRIBE] Hub chain - no need to subscribe to self");
                 }
             }
+            
+            Operation::ResumeSync => {
+                match self.state.sync_progress.get().next.clone() {
+                    Some(cursor) if !is_hub => {
+                        eprintln!("[SYNC] Resuming sync at {:?}", cursor.collection);
+                        self.request_sync_page(cursor);
+                    }
+                    _ => eprintln!("[SYNC] No sync to resume"),
+                }
+            }
         }
     }
 
     async fn execute_message(&mut self, message: Message) -> Self::Response {
-        let current_chain = self.runtime.chain_id().to_string();
-        let marketplace_chain = self.state.marketplace_chain.get().clone();
-        let is_hub = current_chain == marketplace_chain;
+        let current_chain = self.runtime.chain_id();
+        let is_hub = current_chain == self.hub_chain();
+
+        // A tracked message the receiver rejected comes back to its sender
+        if self.runtime.message_is_bouncing() == Some(true) {
+            self.handle_bounce(message).await;
+            return;
+        }
 
         match message {
             Message::Transfer {
@@ -343,36 +605,62 @@ impl Contract for TicketingContract {
                 target_chain,
                 seller_chain,
                 sale_price,
+                ..
             } => {
                 self.receive_ticket(ticket, target_chain, seller_chain, sale_price).await;
             }
             
+            Message::TransferBatch {
+                tickets,
+                target_chain,
+                seller_chain,
+            } => {
+                for ticket in tickets {
+                    self.receive_ticket(ticket, target_chain, seller_chain, None).await;
+                }
+            }
+            
             Message::Claim {
                 source_chain,
                 ticket_id,
                 requester_chain,
+                claimant,
                 new_owner,
                 sale_price,
             } => {
+                self.assert_message_from(requester_chain);
                 let ticket = self.get_ticket(&ticket_id).await;
                 assert_eq!(ticket.owner_chain, source_chain, "Ticket not owned by source");
-                self.transfer(ticket, source_chain, requester_chain, new_owner, sale_price).await;
+                assert!(
+                    claimant == ticket.owner || self.is_approved_operator(&ticket, &claimant).await,
+                    "Not the ticket owner or an approved operator"
+                );
+                self.transfer(ticket, source_chain, requester_chain, new_owner, sale_price, false).await;
             }
             
             // Hub-bound messages - only processed on the hub
             Message::CreateEventOnHub { event } => {
                 if is_hub {
-                    self.create_event_local(event).await;
+                    self.assert_message_from(event.organizer_chain);
+                    let write = PendingWrite::Event(event.id.clone());
+                    if self.create_event_local(event).await {
+                        self.answer_write(Message::WriteConfirmed { write });
+                    } else {
+                        self.answer_write(Message::WriteRejected { write });
+                    }
                 } else {
                     eprintln!("[WARN] CreateEventOnHub received on non-hub chain");
                 }
             }
             
-            Message::CreateListingOnHub { listing } => {
+            Message::CreateListingOnHub { ticket_id, seller_chain, seller, price } => {
                 if is_hub {
-                    // Verify the ticket exists on hub (synced via MintTicketOnHub)
-                    let ticket_id = listing.ticket_id.clone();
-                    self.state.listings.insert(&ticket_id, listing).unwrap();
+                    // Re-checks ownership against the hub's copy of the ticket
+                    self.assert_message_from(seller_chain);
+                    self.create_listing_local(seller_chain, seller, ticket_id.clone(), price).await;
+                    self.answer_write(Message::WriteConfirmed {
+                        write: PendingWrite::Listing(ticket_id),
+                    });
                     eprintln!("[HUB] Listing created from remote chain");
                 } else {
                     eprintln!("[WARN] CreateListingOnHub received on non-hub chain");
@@ -381,12 +669,14 @@ impl Contract for TicketingContract {
             
             Message::CancelListingOnHub { ticket_id, seller_chain, seller } => {
                 if is_hub {
+                    self.assert_message_from(seller_chain);
                     self.cancel_listing_local(seller_chain, seller, ticket_id).await;
                 }
             }
             
             Message::BuyListingOnHub { ticket_id, buyer_chain, buyer, price } => {
                 if is_hub {
+                    self.assert_message_from(buyer_chain);
                     self.buy_listing_local(buyer_chain, buyer, ticket_id, price).await;
                 }
             }
@@ -395,87 +685,253 @@ impl Contract for TicketingContract {
                 if is_hub {
                     // Store ticket reference on hub
                     let ticket_id = ticket.ticket_id.clone();
+                    self.reserve_seat(&ticket.event_id, &ticket.seat).await;
                     self.state.tickets.insert(&ticket_id, ticket).unwrap();
                     eprintln!("[HUB] Ticket reference stored from remote mint");
                 }
             }
             
-            Message::MintTicketRequest { minter_chain, owner, event_id, seat, blob_hash, image_url } => {
+            Message::MintTicketRequest {
+                minter_chain,
+                owner,
+                event_id,
+                seat,
+                blob_hash,
+                image_url,
+                transfer_policy,
+                presale_proof,
+                owner_signed,
+                pending_ticket_id,
+            } => {
                 if is_hub {
                     // Hub processes mint request from user chain
-                    self.mint_ticket(minter_chain, owner, event_id, seat, blob_hash, image_url).await;
+                    self.assert_message_from(minter_chain);
+                    let ticket = self
+                        .mint_ticket(
+                            minter_chain,
+                            owner,
+                            owner_signed,
+                            event_id,
+                            seat,
+                            blob_hash,
+                            image_url,
+                            transfer_policy,
+                            presale_proof,
+                        )
+                        .await;
+                    if let Some(pending_ticket_id) = pending_ticket_id {
+                        self.answer_write(Message::MintConfirmed { pending_ticket_id, ticket });
+                    }
                     eprintln!("[HUB] MintTicketRequest processed from remote chain");
                 } else {
                     eprintln!("[WARN] MintTicketRequest received on non-hub chain");
                 }
             }
             
-            Message::RequestSync { requester_chain } => {
+            Message::MintTicketBatchRequest {
+                minter_chain,
+                owner,
+                event_id,
+                seats,
+                ranges,
+                blob_hash,
+                image_url,
+                transfer_policy,
+                presale_proof,
+                owner_signed,
+                pending_ticket_ids,
+            } => {
                 if is_hub {
-                    // Hub receives sync request - send all current state to the requester
-                    eprintln!("[HUB] Received sync request from chain: {}", requester_chain);
-                    
-                    // Collect all events (convert Cow to owned)
-                    let mut events = Vec::new();
-                    self.state.events.for_each_index_value(|_, event| {
-                        events.push(event.into_owned());
-                        Ok(())
-                    }).await.ok();
-                    
-                    // Collect all tickets (convert Cow to owned)
-                    let mut tickets = Vec::new();
-                    self.state.tickets.for_each_index_value(|_, ticket| {
-                        tickets.push(ticket.into_owned());
-                        Ok(())
-                    }).await.ok();
-                    
-                    // Collect all listings (convert Cow to owned)
-                    let mut listings = Vec::new();
-                    self.state.listings.for_each_index_value(|_, listing| {
-                        listings.push(listing.into_owned());
-                        Ok(())
-                    }).await.ok();
-                    
-                    eprintln!("[HUB] Sending InitialStateSync: {} events, {} tickets, {} listings", 
-                        events.len(), tickets.len(), listings.len());
-                    
-                    // Send initial state to the requesting chain
-                    if let Ok(target_chain) = requester_chain.parse::<ChainId>() {
-                        self.runtime.send_message(target_chain, Message::InitialStateSync {
-                            events,
-                            tickets,
-                            listings,
-                        });
+                    self.assert_message_from(minter_chain);
+                    let seats = ticketing::expand_seats(&seats, &ranges);
+                    let tickets = self
+                        .mint_ticket_batch(
+                            minter_chain,
+                            owner,
+                            owner_signed,
+                            event_id,
+                            seats,
+                            blob_hash,
+                            image_url,
+                            transfer_policy,
+                            presale_proof,
+                        )
+                        .await;
+                    // Optimistic copies were made for every seat, in order, or for none
+                    for (pending_ticket_id, ticket) in pending_ticket_ids.into_iter().zip(tickets) {
+                        self.answer_write(Message::MintConfirmed { pending_ticket_id, ticket });
                     }
+                    eprintln!("[HUB] MintTicketBatchRequest processed from remote chain");
                 } else {
-                    eprintln!("[WARN] RequestSync received on non-hub chain");
+                    eprintln!("[WARN] MintTicketBatchRequest received on non-hub chain");
                 }
             }
             
-            Message::InitialStateSync { events, tickets, listings } => {
-                // User chain receives initial state from hub
-                eprintln!("[SYNC] Received InitialStateSync: {} events, {} tickets, {} listings",
-                    events.len(), tickets.len(), listings.len());
-                
-                // Store all events locally
-                for event in events {
-                    let event_id = event.id.clone();
-                    self.state.events.insert(&event_id, event).unwrap();
+            Message::SetHolderLimitsOnHub { event_id, requester_chain, max_per_holder, max_purchases_per_holder } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.set_holder_limits(requester_chain, event_id, max_per_holder, max_purchases_per_holder).await;
+                } else {
+                    eprintln!("[WARN] SetHolderLimitsOnHub received on non-hub chain");
                 }
-                
-                // Store all tickets locally
-                for ticket in tickets {
-                    let ticket_id = ticket.ticket_id.clone();
-                    self.state.tickets.insert(&ticket_id, ticket).unwrap();
+            }
+            
+            Message::SetPresaleOnHub { event_id, requester_chain, presale } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.set_presale(requester_chain, event_id, presale).await;
+                } else {
+                    eprintln!("[WARN] SetPresaleOnHub received on non-hub chain");
                 }
-                
-                // Store all listings locally  
-                for listing in listings {
-                    let ticket_id = listing.ticket_id.clone();
-                    self.state.listings.insert(&ticket_id, listing).unwrap();
+            }
+            
+            Message::UpdatePresaleAllowlistOnHub { event_id, requester_chain, added, removed } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.update_presale_allowlist(requester_chain, event_id, added, removed).await;
+                } else {
+                    eprintln!("[WARN] UpdatePresaleAllowlistOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::BurnTicketOnHub { ticket_id, requester_chain, owner, reason, release_seat } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.burn_ticket(requester_chain, owner, ticket_id, reason, release_seat).await;
+                } else {
+                    eprintln!("[WARN] BurnTicketOnHub received on non-hub chain");
                 }
-                
-                eprintln!("[SYNC] InitialStateSync complete - local state updated");
+            }
+            
+            Message::ApproveOnHub { ticket_id, requester_chain, owner, operator } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.approve(requester_chain, owner, ticket_id, operator).await;
+                } else {
+                    eprintln!("[WARN] ApproveOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::SetApprovalForAllOnHub { requester_chain, owner, operator, approved } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.set_approval_for_all(requester_chain, owner, operator, approved).await;
+                } else {
+                    eprintln!("[WARN] SetApprovalForAllOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::TicketBurned { ticket_id } => {
+                self.assert_message_from_hub();
+                if let Some(ticket) = self.state.tickets.get(&ticket_id).await.unwrap() {
+                    self.remove_burned_ticket(&ticket_id, &ticket.owner_chain).await;
+                }
+            }
+            
+            Message::WriteConfirmed { write } => {
+                self.assert_message_from_hub();
+                eprintln!("[WRITE] Hub confirmed {:?}", write);
+                self.state.write_status.insert(&write, WriteStatus::Confirmed).unwrap();
+            }
+            
+            Message::WriteRejected { write } => {
+                self.assert_message_from_hub();
+                self.reject_write(write).await;
+            }
+            
+            Message::MintConfirmed { pending_ticket_id, ticket } => {
+                self.assert_message_from_hub();
+                if pending_ticket_id != ticket.ticket_id {
+                    // The hub minted at another index; its copy arrives with the mint delivery
+                    self.roll_back_write(&PendingWrite::Ticket(pending_ticket_id.clone())).await;
+                    self.reserve_seat(&ticket.event_id, &ticket.seat).await;
+                    // The rollback uncounted the mint, which still happened at the other index
+                    if let Some(mut event) = self.state.events.get(&ticket.event_id).await.unwrap() {
+                        event.minted_tickets += 1;
+                        self.state.events.insert(&ticket.event_id, event).unwrap();
+                    }
+                    // ...and released the holding the hub's copy still counts for
+                    // (purchase counts were left alone)
+                    self.record_acquisition(&ticket.event_id, &ticket.owner, &ticket.owner_chain, 1, false)
+                        .await;
+                    self.state
+                        .write_status
+                        .remove(&PendingWrite::Ticket(pending_ticket_id))
+                        .unwrap();
+                }
+                eprintln!("[WRITE] Hub confirmed mint of seat '{}'", ticket.seat);
+                self.state
+                    .write_status
+                    .insert(&PendingWrite::Ticket(ticket.ticket_id), WriteStatus::Confirmed)
+                    .unwrap();
+            }
+            
+            Message::ConvertToCollectiblesOnHub { event_id, requester_chain, terms } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.convert_to_collectibles(requester_chain, event_id, terms).await;
+                } else {
+                    eprintln!("[WARN] ConvertToCollectiblesOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::RedeemTicketOnHub { ticket_id, scanner_chain, gate } => {
+                if is_hub {
+                    self.assert_message_from(scanner_chain);
+                    self.redeem_ticket(scanner_chain, ticket_id, gate).await;
+                } else {
+                    eprintln!("[WARN] RedeemTicketOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::SubmitCheckInsOnHub { event_id, scanner_chain, check_ins } => {
+                if is_hub {
+                    self.assert_message_from(scanner_chain);
+                    self.submit_check_ins(scanner_chain, event_id, check_ins).await;
+                } else {
+                    eprintln!("[WARN] SubmitCheckInsOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::SetTransferLockoutOnHub { event_id, requester_chain, lockout_secs } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.set_transfer_lockout(requester_chain, event_id, lockout_secs).await;
+                } else {
+                    eprintln!("[WARN] SetTransferLockoutOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::GrantEventRoleOnHub { event_id, granter_chain, chain, role } => {
+                if is_hub {
+                    self.assert_message_from(granter_chain);
+                    self.set_event_role(granter_chain, event_id, chain, role, true).await;
+                } else {
+                    eprintln!("[WARN] GrantEventRoleOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::RevokeEventRoleOnHub { event_id, granter_chain, chain, role } => {
+                if is_hub {
+                    self.assert_message_from(granter_chain);
+                    self.set_event_role(granter_chain, event_id, chain, role, false).await;
+                } else {
+                    eprintln!("[WARN] RevokeEventRoleOnHub received on non-hub chain");
+                }
+            }
+            
+            Message::RequestSyncPage { requester_chain, cursor } => {
+                if is_hub {
+                    self.assert_message_from(requester_chain);
+                    self.serve_sync_page(requester_chain, cursor).await;
+                } else {
+                    eprintln!("[WARN] RequestSyncPage received on non-hub chain");
+                }
+            }
+            
+            Message::SyncPage { cursor, records, next, hub_sequence } => {
+                self.assert_message_from_hub();
+                self.apply_sync_page(cursor, records, next, hub_sequence).await;
             }
         }
     }
@@ -493,38 +949,97 @@ impl Contract for TicketingContract {
             eprintln!("[STREAM] Processing {} new events from hub", indices.len());
             
             for index in indices {
-                let event: StreamEvent = self.runtime.read_event(
+                let SequencedEvent { sequence, event } = self.runtime.read_event(
                     hub_chain_id,
                     MARKETPLACE_STREAM.into(),
                     index,
                 );
+                // Skip events replayed after a resubscription, and those a completed sync
+                // already reflects
+                let applied = *self.state.applied_sequence.get();
+                if sequence <= applied
+                    || (sequence <= *self.state.snapshot_sequence.get() && event.is_covered_by_sync())
+                {
+                    eprintln!("[STREAM] Skipping already applied event #{}", sequence);
+                    continue;
+                }
+                self.state.applied_sequence.set(sequence);
+                if sequence > *self.state.hub_sequence.get() {
+                    self.state.hub_sequence.set(sequence);
+                }
                 
                 match event {
-                    StreamEvent::EventCreated { event } => {
+                    StreamEvent::EventCreated { event } | StreamEvent::EventUpdated { event } => {
                         // Sync event to local state
                         let event_id = event.id.clone();
+                        self.index_event_organizer(&event).await;
                         self.state.events.insert(&event_id, event).unwrap();
                         eprintln!("[SYNC] Event '{}' synced from hub", event_id.value);
                     }
                     StreamEvent::TicketMinted { ticket } => {
-                        // Sync ticket to local state
-                        let ticket_id = ticket.ticket_id.clone();
-                        let owner_chain = ticket.owner_chain.clone();
-                        self.state.tickets.insert(&ticket_id, ticket).unwrap();
-                        // Also sync ownership so ticketsByOwner and myTickets queries work
-                        let mut owned = self
-                            .state
-                            .owned_ticket_ids
-                            .get(&owner_chain)
+                        self.sync_ticket(ticket, true).await;
+                        eprintln!("[SYNC] Ticket and ownership synced from hub");
+                    }
+                    StreamEvent::TicketsMinted { tickets } => {
+                        let count = tickets.len();
+                        for ticket in tickets {
+                            self.sync_ticket(ticket, true).await;
+                        }
+                        eprintln!("[SYNC] {} tickets and ownership synced from hub", count);
+                    }
+                    StreamEvent::TicketTransferred {
+                        ticket,
+                        previous_owner,
+                        previous_chain,
+                        sale_price,
+                        transferred_at,
+                    } => {
+                        let ticket_id = ticket.ticket_id.clone();
+                        let (new_owner, new_chain) = (ticket.owner, ticket.owner_chain);
+                        // The local copy (if any) still names the previous owner
+                        self.remove_owned_ticket(&previous_chain, &ticket_id).await;
+                        self.sync_ticket(ticket, sale_price.is_some()).await;
+                        self.state.listings.remove(&ticket_id).unwrap();
+                        self.state.ticket_approvals.remove(&ticket_id).unwrap();
+                        // Chains taking part in the transfer already recorded it
+                        let recorded = self
+                            .state
+                            .ticket_history
+                            .get(&ticket_id)
                             .await
                             .unwrap()
-                            .unwrap_or_default();
-                        owned.insert(ticket_id);
-                        self.state
-                            .owned_ticket_ids
-                            .insert(&owner_chain, owned)
-                            .unwrap();
-                        eprintln!("[SYNC] Ticket and ownership synced from hub");
+                            .and_then(|history| history.ownership_history.last().cloned())
+                            .is_some_and(|record| {
+                                record.owner == new_owner && record.owner_chain == new_chain
+                            });
+                        if !recorded {
+                            self.record_transfer_history(
+                                &ticket_id,
+                                new_owner,
+                                new_chain,
+                                sale_price,
+                                transferred_at,
+                            )
+                            .await;
+                        }
+                        eprintln!(
+                            "[SYNC] Ticket transfer from {} synced from hub",
+                            previous_owner
+                        );
+                    }
+                    StreamEvent::RoyaltyAccrued { organizer_chain, amount, .. } => {
+                        self.credit_royalty(&organizer_chain, amount).await;
+                        eprintln!(
+                            "[SYNC] Royalty of {} for {} synced from hub",
+                            amount, organizer_chain
+                        );
+                    }
+                    StreamEvent::RoyaltyReversed { organizer_chain, amount, .. } => {
+                        self.debit_royalty(&organizer_chain, amount).await;
+                        eprintln!(
+                            "[SYNC] Royalty of {} for {} reversed by hub",
+                            amount, organizer_chain
+                        );
                     }
                     StreamEvent::ListingCreated { listing } | StreamEvent::ListingUpdated { listing } => {
                         // Sync listing to local state
@@ -532,6 +1047,51 @@ impl Contract for TicketingContract {
                         self.state.listings.insert(&ticket_id, listing).unwrap();
                         eprintln!("[SYNC] Listing synced from hub");
                     }
+                    StreamEvent::TicketBurned { ticket_id, event_id, owner_chain, released_seat, .. } => {
+                        self.remove_burned_ticket(&ticket_id, &owner_chain).await;
+                        if let Some(seat) = released_seat {
+                            self.release_seat(&event_id, &seat).await;
+                        }
+                        eprintln!("[SYNC] Ticket burn synced from hub");
+                    }
+                    StreamEvent::CollectiblesConverted { event_id, terms, ticket_ids } => {
+                        for ticket_id in &ticket_ids {
+                            self.apply_collectible(ticket_id, &terms).await;
+                        }
+                        eprintln!(
+                            "[SYNC] {} collectibles for event '{}' synced from hub",
+                            ticket_ids.len(),
+                            event_id.value
+                        );
+                    }
+                    StreamEvent::TicketRedeemed { ticket_id, redemption } => {
+                        self.apply_redemption(&ticket_id, redemption).await;
+                        eprintln!("[SYNC] Ticket redemption synced from hub");
+                    }
+                    StreamEvent::FraudAlertRaised { alert } => {
+                        let mut alerts = self
+                            .state
+                            .fraud_alerts
+                            .get(&alert.event_id)
+                            .await
+                            .unwrap()
+                            .unwrap_or_default();
+                        let event_id = alert.event_id.clone();
+                        alerts.push(alert);
+                        self.state.fraud_alerts.insert(&event_id, alerts).unwrap();
+                        eprintln!("[SYNC] Fraud alert synced from hub");
+                    }
+                    StreamEvent::PresaleUpdated { event_id, presale } => {
+                        match presale {
+                            Some(presale) => self.state.presales.insert(&event_id, presale).unwrap(),
+                            None => self.state.presales.remove(&event_id).unwrap(),
+                        }
+                        eprintln!("[SYNC] Presale for event '{}' synced from hub", event_id.value);
+                    }
+                    StreamEvent::EventRolesUpdated { event_id, chain, roles } => {
+                        self.store_event_roles(&event_id, chain, roles).await;
+                        eprintln!("[SYNC] Roles for event '{}' synced from hub", event_id.value);
+                    }
                 }
             }
         }
@@ -545,163 +1105,1792 @@ impl Contract for TicketingContract {
 impl TicketingContract {
     /// Helper to subscribe to the hub's marketplace event stream
     fn subscribe_to_hub(&mut self) {
-        let params = self.runtime.application_parameters();
-        if let Ok(hub_chain_id) = params.marketplace_chain.parse::<ChainId>() {
-            let app_id = self.runtime.application_id().forget_abi();
-            self.runtime.subscribe_to_events(
-                hub_chain_id,
-                app_id,
-                MARKETPLACE_STREAM.into(),
-            );
-            eprintln!("[SUBSCRIBE] Subscribed to hub stream: {}", params.marketplace_chain);
+        let hub_chain_id = self.hub_chain();
+        let app_id = self.runtime.application_id().forget_abi();
+        self.runtime.subscribe_to_events(
+            hub_chain_id,
+            app_id,
+            MARKETPLACE_STREAM.into(),
+        );
+        eprintln!("[SUBSCRIBE] Subscribed to hub stream: {}", hub_chain_id);
+    }
+
+    /// Converts and stores records exported from an older deployment (on hub), rebuilding
+    /// the derived indexes, and emits them so subscribed chains pick them up.
+    /// Records already stored are skipped, so re-sending a batch credits nothing twice.
+    async fn import_legacy_state(
+        &mut self,
+        events: Vec<LegacyEvent>,
+        tickets: Vec<LegacyTicket>,
+        listings: Vec<LegacyListing>,
+        histories: Vec<LegacyTicketHistory>,
+        balances: Vec<LegacyBalance>,
+    ) {
+        use ticketing::TicketHistory;
+        let mut event_count = 0;
+        for event in events {
+            if self.state.events.contains_key(&event.id).await.unwrap() {
+                continue;
+            }
+            let event = Event::try_from(event).unwrap_or_else(|error| panic!("{}", error));
+            self.index_event_organizer(&event).await;
+            self.state.events.insert(&event.id, event.clone()).unwrap();
+            self.emit_event(StreamEvent::EventCreated { event });
+            event_count += 1;
+        }
+
+        let mut imported = Vec::with_capacity(tickets.len());
+        for ticket in tickets {
+            if self.state.tickets.contains_key(&ticket.ticket_id).await.unwrap() {
+                continue;
+            }
+            let ticket = Ticket::try_from(ticket).unwrap_or_else(|error| panic!("{}", error));
+            if !ticketing::is_placeholder_blob(&ticket.metadata_hash) {
+                self.runtime.assert_data_blob_exists(ticket.metadata_hash);
+            }
+            self.sync_ticket(ticket.clone(), false).await;
+            imported.push(ticket);
+        }
+        let ticket_count = imported.len();
+        if !imported.is_empty() {
+            self.emit_event(StreamEvent::TicketsMinted { tickets: imported });
+        }
+
+        for listing in listings {
+            if self.state.listings.contains_key(&listing.ticket_id).await.unwrap() {
+                continue;
+            }
+            let listing = Listing::try_from(listing).unwrap_or_else(|error| panic!("{}", error));
+            self.state.listings.insert(&listing.ticket_id, listing.clone()).unwrap();
+            self.emit_event(StreamEvent::ListingCreated { listing });
+        }
+
+        for history in histories {
+            let ticket_id = history.ticket_id.clone();
+            if self.state.ticket_history.contains_key(&ticket_id).await.unwrap() {
+                continue;
+            }
+            let history =
+                TicketHistory::try_from(history).unwrap_or_else(|error| panic!("{}", error));
+            self.state.ticket_history.insert(&ticket_id, history).unwrap();
+        }
+
+        for balance in balances {
+            let chain =
+                migration::parse_chain(&balance.chain).unwrap_or_else(|error| panic!("{}", error));
+            if self.state.royalty_balances.contains_key(&chain).await.unwrap() {
+                continue;
+            }
+            self.credit_royalty(&chain, balance.pending).await;
+        }
+
+        eprintln!("[MIGRATION] Imported {} events and {} tickets", event_count, ticket_count);
+    }
+
+    /// Emits a marketplace stream event (on hub) under the next sequence and records the
+    /// events, tickets and listings it touched for delta syncs.
+    fn emit_event(&mut self, event: StreamEvent) {
+        let sequence = *self.state.hub_sequence.get() + 1;
+        self.state.hub_sequence.set(sequence);
+        match &event {
+            StreamEvent::EventCreated { event } | StreamEvent::EventUpdated { event } => {
+                self.record_event_change(&event.id, sequence);
+            }
+            StreamEvent::TicketMinted { ticket } => {
+                self.record_ticket_change(&ticket.ticket_id, sequence);
+            }
+            StreamEvent::TicketTransferred { ticket, .. } => {
+                // Transfers also drop the ticket's listing
+                self.record_ticket_change(&ticket.ticket_id, sequence);
+                self.record_listing_change(&ticket.ticket_id, sequence);
+            }
+            StreamEvent::TicketsMinted { tickets } => {
+                for ticket in tickets {
+                    self.record_ticket_change(&ticket.ticket_id, sequence);
+                }
+            }
+            StreamEvent::ListingCreated { listing } | StreamEvent::ListingUpdated { listing } => {
+                self.record_listing_change(&listing.ticket_id, sequence);
+            }
+            StreamEvent::TicketBurned { ticket_id, .. } => {
+                self.record_ticket_change(ticket_id, sequence);
+                self.record_listing_change(ticket_id, sequence);
+            }
+            StreamEvent::CollectiblesConverted { ticket_ids, .. } => {
+                for ticket_id in ticket_ids {
+                    self.record_ticket_change(ticket_id, sequence);
+                }
+            }
+            StreamEvent::TicketRedeemed { ticket_id, .. } => {
+                self.record_ticket_change(ticket_id, sequence);
+            }
+            // Not part of paginated sync
+            StreamEvent::RoyaltyAccrued { .. }
+            | StreamEvent::RoyaltyReversed { .. }
+            | StreamEvent::FraudAlertRaised { .. }
+            | StreamEvent::PresaleUpdated { .. }
+            | StreamEvent::EventRolesUpdated { .. } => {}
+        }
+        self.runtime.emit(MARKETPLACE_STREAM.into(), &SequencedEvent { sequence, event });
+    }
+
+    /// Records that an event changed at `sequence`, for delta syncs and the sync walk.
+    fn record_event_change(&mut self, event_id: &EventId, sequence: u64) {
+        self.state.event_changes.insert(event_id, sequence).unwrap();
+        self.state.event_log.push((sequence, event_id.clone()));
+    }
+
+    /// Records that a ticket changed at `sequence`, for delta syncs and the sync walk.
+    fn record_ticket_change(&mut self, ticket_id: &TicketId, sequence: u64) {
+        self.state.ticket_changes.insert(ticket_id, sequence).unwrap();
+        self.state.ticket_log.push((sequence, ticket_id.clone()));
+    }
+
+    /// Records that a listing changed at `sequence`, for delta syncs and the sync walk.
+    fn record_listing_change(&mut self, ticket_id: &TicketId, sequence: u64) {
+        self.state.listing_changes.insert(ticket_id, sequence).unwrap();
+        self.state.listing_log.push((sequence, ticket_id.clone()));
+    }
+
+    /// Sends a sync page request for `cursor` to the hub.
+    fn request_sync_page(&mut self, cursor: SyncCursor) {
+        let hub_chain = self.hub_chain();
+        let requester_chain = self.runtime.chain_id();
+        self.runtime.send_message(hub_chain, Message::RequestSyncPage { requester_chain, cursor });
+    }
+
+    /// Answers a sync request (on hub) with up to `SYNC_PAGE_SIZE` records after the cursor.
+    /// Only keys are scanned up to the cursor; values are loaded for the page alone.
+    /// Delta requests (`since`) page over the change indexes instead, reporting deletions.
+    async fn serve_sync_page(&mut self, requester_chain: ChainId, cursor: SyncCursor) {
+        let (records, next) = match cursor.collection {
+            SyncCollection::Events => {
+                let (keys, next) =
+                    page_changes(&self.state.event_log, &self.state.event_changes, &cursor).await;
+                let mut events = Vec::with_capacity(keys.len());
+                for event_id in keys {
+                    events.extend(self.state.events.get(&event_id).await.unwrap());
+                }
+                (SyncRecords::Events(events), next)
+            }
+            SyncCollection::Tickets => {
+                let (keys, next) =
+                    page_changes(&self.state.ticket_log, &self.state.ticket_changes, &cursor).await;
+                let mut tickets = Vec::with_capacity(keys.len());
+                let mut removed = Vec::new();
+                for ticket_id in keys {
+                    match self.state.tickets.get(&ticket_id).await.unwrap() {
+                        Some(ticket) => tickets.push(ticket),
+                        None => removed.push(ticket_id),
+                    }
+                }
+                (SyncRecords::Tickets { tickets, removed }, next)
+            }
+            SyncCollection::Listings => {
+                let (keys, next) =
+                    page_changes(&self.state.listing_log, &self.state.listing_changes, &cursor).await;
+                let mut listings = Vec::with_capacity(keys.len());
+                let mut removed = Vec::new();
+                for ticket_id in keys {
+                    match self.state.listings.get(&ticket_id).await.unwrap() {
+                        Some(listing) => listings.push(listing),
+                        None => removed.push(ticket_id),
+                    }
+                }
+                (SyncRecords::Listings { listings, removed }, next)
+            }
+        };
+        let hub_sequence = *self.state.hub_sequence.get();
+        eprintln!(
+            "[HUB] Sending {} {:?} to {} (more: {})",
+            records.len(),
+            cursor.collection,
+            requester_chain,
+            next.is_some()
+        );
+        self.runtime.send_message(
+            requester_chain,
+            Message::SyncPage { cursor, records, next, hub_sequence },
+        );
+    }
+
+    /// Stores a page of hub state and requests the next one.
+    /// The stored cursor lets `ResumeSync` pick up where an interrupted sync stopped.
+    async fn apply_sync_page(
+        &mut self,
+        cursor: SyncCursor,
+        records: SyncRecords,
+        next: Option<SyncCursor>,
+        hub_sequence: u64,
+    ) {
+        let mut progress = self.state.sync_progress.get().clone();
+        // Duplicate answers (e.g. after a resume) would otherwise fork the request chain
+        if progress.next.as_ref() != Some(&cursor) {
+            eprintln!("[SYNC] Ignoring unexpected page for {:?}", cursor.collection);
+            return;
+        }
+        eprintln!("[SYNC] Received {} {:?}", records.len(), cursor.collection);
+
+        match records {
+            SyncRecords::Events(events) => {
+                for event in events {
+                    let event_id = event.id.clone();
+                    self.index_event_organizer(&event).await;
+                    self.state.events.insert(&event_id, event).unwrap();
+                }
+            }
+            SyncRecords::Tickets { tickets, removed } => {
+                // Pages do not say how a holder acquired the ticket
+                for ticket in tickets {
+                    self.sync_ticket(ticket, false).await;
+                }
+                for ticket_id in removed {
+                    if let Some(ticket) = self.state.tickets.get(&ticket_id).await.unwrap() {
+                        self.remove_burned_ticket(&ticket_id, &ticket.owner_chain).await;
+                    }
+                }
+            }
+            SyncRecords::Listings { listings, removed } => {
+                for listing in listings {
+                    let ticket_id = listing.ticket_id.clone();
+                    self.state.listings.insert(&ticket_id, listing).unwrap();
+                }
+                for ticket_id in removed {
+                    self.state.listings.remove(&ticket_id).unwrap();
+                }
+            }
+        }
+
+        if progress.pages_received == 0 {
+            progress.base_sequence = hub_sequence;
+        }
+        if hub_sequence > *self.state.hub_sequence.get() {
+            self.state.hub_sequence.set(hub_sequence);
+        }
+        progress.pages_received += 1;
+        progress.completed = next.is_none();
+        progress.next = next.clone();
+        match next {
+            Some(next) => self.request_sync_page(next),
+            None => {
+                // Every page reflects the hub up to the first page's sequence
+                let snapshot = (*self.state.snapshot_sequence.get()).max(progress.base_sequence);
+                self.state.snapshot_sequence.set(snapshot);
+                eprintln!("[SYNC] Sync complete - local state reflects hub sequence {}", snapshot);
+            }
+        }
+        self.state.sync_progress.set(progress);
+    }
+
+    /// Returns the hub chain: from the parameters, or the creation chain recorded at instantiation.
+    fn hub_chain(&mut self) -> ChainId {
+        self.runtime
+            .application_parameters()
+            .marketplace_chain
+            .or(*self.state.marketplace_chain.get())
+            .expect("marketplace chain not set")
+    }
+
+    /// Stores a ticket received from the hub along with its derived indexes, moving
+    /// ownership and holder counts off the previous local copy when the owner changed.
+    /// `is_purchase` says whether a newly counted holding was bought.
+    async fn sync_ticket(&mut self, ticket: Ticket, is_purchase: bool) {
+        let ticket_id = ticket.ticket_id.clone();
+        let owner_chain = ticket.owner_chain;
+        match self.state.tickets.get(&ticket_id).await.unwrap() {
+            Some(previous) if previous.owner == ticket.owner && previous.owner_chain == owner_chain => {}
+            Some(previous) => {
+                if previous.owner_chain != owner_chain {
+                    self.remove_owned_ticket(&previous.owner_chain, &ticket_id).await;
+                }
+                self.record_release(&previous.event_id, &previous.owner, &previous.owner_chain)
+                    .await;
+                self.record_acquisition(&ticket.event_id, &ticket.owner, &owner_chain, 1, is_purchase)
+                    .await;
+            }
+            None => {
+                self.record_acquisition(&ticket.event_id, &ticket.owner, &owner_chain, 1, is_purchase)
+                    .await;
+            }
         }
+        self.reserve_seat(&ticket.event_id, &ticket.seat).await;
+        self.note_verified_blob(ticket.metadata_hash);
+        self.state.tickets.insert(&ticket_id, ticket).unwrap();
+        // Also sync ownership so ticketsByOwner and myTickets queries work
+        let mut owned = self
+            .state
+            .owned_ticket_ids
+            .get(&owner_chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        owned.insert(ticket_id);
+        self.state
+            .owned_ticket_ids
+            .insert(&owner_chain, owned)
+            .unwrap();
     }
 
     /// Helper to forward a message to the hub chain
     fn forward_to_hub(&mut self, message: Message) {
-        // Read marketplace_chain from parameters (always available via app ID)
-        let params = self.runtime.application_parameters();
-        let marketplace_chain = &params.marketplace_chain;
-        if let Ok(hub_chain_id) = marketplace_chain.parse::<ChainId>() {
-            self.runtime.send_message(hub_chain_id, message);
-            eprintln!("[FORWARD] Message sent to hub: {}", marketplace_chain);
-        } else {
-            panic!("Invalid marketplace chain ID: {}", marketplace_chain);
+        let hub_chain_id = self.hub_chain();
+        self.runtime.send_message(hub_chain_id, message);
+        eprintln!("[FORWARD] Message sent to hub: {}", hub_chain_id);
+    }
+
+    /// Forwards a message backing an optimistic write to the hub with tracking,
+    /// so a rejection bounces back and the write can be rolled back.
+    fn forward_to_hub_tracked(&mut self, message: Message) {
+        let hub_chain_id = self.hub_chain();
+        self.runtime
+            .prepare_message(message)
+            .with_tracking()
+            .send_to(hub_chain_id);
+        eprintln!("[FORWARD] Tracked message sent to hub: {}", hub_chain_id);
+    }
+
+    /// Panics unless the current message was sent by `claimed`, so a chain cannot send
+    /// requests on behalf of another chain.
+    fn assert_message_from(&mut self, claimed: ChainId) {
+        let origin = self
+            .runtime
+            .message_origin_chain_id()
+            .expect("only called while handling messages");
+        assert_eq!(origin, claimed, "Message claims to come from another chain");
+    }
+
+    /// Panics unless the current message was sent by the hub.
+    fn assert_message_from_hub(&mut self) {
+        let hub_chain = self.hub_chain();
+        self.assert_message_from(hub_chain);
+    }
+
+    /// Records an optimistic write as waiting for the hub.
+    fn track_write(&mut self, write: PendingWrite) {
+        self.state.write_status.insert(&write, WriteStatus::Pending).unwrap();
+    }
+
+    /// Answers the chain that sent the current message about its optimistic write (on hub).
+    fn answer_write(&mut self, answer: Message) {
+        let origin = self
+            .runtime
+            .message_origin_chain_id()
+            .expect("write answers are only sent while handling messages");
+        self.runtime.send_message(origin, answer);
+    }
+
+    /// Handles a tracked message the receiver rejected: restores bounced transfers and
+    /// rolls back the optimistic write behind a hub-bound message, if it made one.
+    async fn handle_bounce(&mut self, message: Message) {
+        let write = match message {
+            Message::CreateEventOnHub { event } => Some(PendingWrite::Event(event.id)),
+            Message::MintTicketRequest { pending_ticket_id, .. } => {
+                pending_ticket_id.map(PendingWrite::Ticket)
+            }
+            Message::MintTicketBatchRequest { pending_ticket_ids, .. } => {
+                for ticket_id in pending_ticket_ids {
+                    self.reject_write(PendingWrite::Ticket(ticket_id)).await;
+                }
+                None
+            }
+            Message::CreateListingOnHub { ticket_id, .. } => Some(PendingWrite::Listing(ticket_id)),
+            Message::Transfer {
+                ticket,
+                seller_chain,
+                sale_price,
+                previous_owner,
+                previous_sale_price,
+                sold_listing,
+                ..
+            } => {
+                self.restore_bounced_transfer(
+                    ticket,
+                    seller_chain,
+                    sale_price,
+                    previous_owner,
+                    previous_sale_price,
+                    sold_listing,
+                )
+                .await;
+                None
+            }
+            other => {
+                eprintln!("[BOUNCE] Message rejected by receiver: {:?}", other);
+                None
+            }
+        };
+        if let Some(write) = write {
+            self.reject_write(write).await;
+        }
+    }
+
+    /// Rolls back an optimistic write the hub rejected and records the rejection.
+    async fn reject_write(&mut self, write: PendingWrite) {
+        eprintln!("[WRITE] Hub rejected {:?}, rolling back", write);
+        self.roll_back_write(&write).await;
+        self.state.write_status.insert(&write, WriteStatus::Rejected).unwrap();
+    }
+
+    /// Removes the local copy written optimistically, along with its derived indexes.
+    async fn roll_back_write(&mut self, write: &PendingWrite) {
+        match write {
+            PendingWrite::Event(event_id) => {
+                let Some(event) = self.state.events.get(event_id).await.unwrap() else {
+                    return;
+                };
+                let mut organized = self
+                    .state
+                    .events_by_organizer
+                    .get(&event.organizer_chain)
+                    .await
+                    .unwrap()
+                    .unwrap_or_default();
+                organized.remove(event_id);
+                self.state
+                    .events_by_organizer
+                    .insert(&event.organizer_chain, organized)
+                    .unwrap();
+                self.state.events.remove(event_id).unwrap();
+            }
+            PendingWrite::Ticket(ticket_id) => {
+                let Some(ticket) = self.state.tickets.get(ticket_id).await.unwrap() else {
+                    return;
+                };
+                self.remove_burned_ticket(ticket_id, &ticket.owner_chain).await;
+                self.release_seat(&ticket.event_id, &ticket.seat).await;
+                self.state.ticket_history.remove(ticket_id).unwrap();
+                if let Some(mut event) = self.state.events.get(&ticket.event_id).await.unwrap() {
+                    event.minted_tickets = event.minted_tickets.saturating_sub(1);
+                    self.state.events.insert(&ticket.event_id, event).unwrap();
+                }
+            }
+            PendingWrite::Listing(ticket_id) => {
+                self.state.listings.remove(ticket_id).unwrap();
+            }
+        }
+    }
+
+    /// Resolves the event ID for a new event of `organizer_chain` (on the organizer chain).
+    /// Slugs are scoped to the organizer; an empty slug takes the next free nonce.
+    async fn assign_event_id(&mut self, organizer_chain: &ChainId, requested: EventId) -> EventId {
+        let nonce = self.state.event_nonces.get(organizer_chain).await.unwrap().unwrap_or(0);
+        let events = &self.state.events;
+        let (event_id, next_nonce) =
+            EventId::resolve(organizer_chain, &requested.value, nonce, |candidate| async move {
+                events.contains_key(&candidate).await.unwrap()
+            })
+            .await;
+        if let Some(next_nonce) = next_nonce {
+            self.state.event_nonces.insert(organizer_chain, next_nonce).unwrap();
         }
+        event_id
+    }
+
+    /// Adds an event to the per-organizer index
+    async fn index_event_organizer(&mut self, event: &Event) {
+        let mut organized = self
+            .state
+            .events_by_organizer
+            .get(&event.organizer_chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        organized.insert(event.id.clone());
+        self.state
+            .events_by_organizer
+            .insert(&event.organizer_chain, organized)
+            .unwrap();
     }
 
-    /// Creates an event locally (on hub)
-    async fn create_event_local(&mut self, event: Event) {
+    /// Creates an event locally (on hub).
+    /// Returns false (without panicking) if the ID is outside the organizer's namespace or
+    /// already taken, so forwarded requests cannot crash the hub.
+    async fn create_event_local(&mut self, event: Event) -> bool {
         assert!(event.royalty_bps <= MAX_BPS, "royalty_bps exceeds maximum");
-        assert!(
-            self.state.events.get(&event.id).await.unwrap().is_none(),
-            "event already exists"
-        );
+        if !event.id.is_scoped_to(&event.organizer_chain) {
+            eprintln!(
+                "[CREATE_EVENT] Rejected '{}': outside namespace of organizer {}",
+                event.id.value, event.organizer_chain
+            );
+            return false;
+        }
+        if self.state.events.get(&event.id).await.unwrap().is_some() {
+            eprintln!("[CREATE_EVENT] Rejected '{}': event already exists", event.id.value);
+            return false;
+        }
         
         let event_id = event.id.clone();
+        self.index_event_organizer(&event).await;
         self.state.events.insert(&event_id, event.clone()).unwrap();
         
         // Emit event to stream for subscribers to sync
-        self.runtime.emit(
-            MARKETPLACE_STREAM.into(),
-            &StreamEvent::EventCreated { event },
-        );
+        self.emit_event(StreamEvent::EventCreated { event });
         
         eprintln!("[CREATE_EVENT] Event '{}' created on hub and emitted to stream", event_id.value);
+        true
+    }
+
+    /// Stores optimistic copies of tickets minted on this user chain so they show up before
+    /// the hub answers, and returns their IDs in seat order. Copies are made for every seat
+    /// or, if the local event copy would refuse any of them, for none.
+    #[allow(clippy::too_many_arguments)]
+    async fn mint_optimistically(
+        &mut self,
+        minter_chain: ChainId,
+        owner: AccountOwner,
+        event_id: &EventId,
+        seats: &[String],
+        blob_hash: DataBlobHash,
+        image_url: &Option<String>,
+        transfer_policy: Option<TransferPolicy>,
+    ) -> Vec<TicketId> {
+        let Some(mut event) = self.state.events.get(event_id).await.unwrap() else {
+            return Vec::new();
+        };
+        let mut batch_seats = BTreeSet::new();
+        let distinct = seats.iter().all(|seat| {
+            ticketing::normalize_seat(seat).is_none_or(|normalized| batch_seats.insert(normalized))
+        });
+        if !distinct
+            || seats.len() > MAX_MINT_BATCH
+            || !self.has_event_role(&event, &minter_chain, EventRole::Minter).await
+            || !event.has_capacity_for(seats.len())
+            || !event.is_on_sale(self.now_secs())
+            || transfer_policy.is_some_and(|policy| !policy.is_at_least_as_strict_as(event.transfer_policy))
+        {
+            return Vec::new();
+        }
+
+        let marketplace_chain = self.hub_chain();
+        let application_id = self.runtime.application_id().forget_abi();
+        let minted_at = self.runtime.system_time().micros() / 1000;
+        let mut owned = self
+            .state
+            .owned_ticket_ids
+            .get(&minter_chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+
+        use ticketing::{AcquisitionType, OwnershipRecord, TicketHistory};
+        let mut ticket_ids = Vec::with_capacity(seats.len());
+        for seat in seats {
+            let ticket_id = Ticket::create_ticket_id(
+                &marketplace_chain,
+                &application_id,
+                event_id,
+                seat,
+                &minter_chain,
+                &blob_hash,
+                event.minted_tickets,
+            )
+            .expect("failed to create ticket id");
+
+            let ticket = Ticket {
+                ticket_id: ticket_id.clone(),
+                event_id: event_id.clone(),
+                event_name: event.name.clone(),
+                seat: seat.clone(),
+                organizer_chain: event.organizer_chain,
+                owner_chain: minter_chain,
+                owner,
+                minter_chain,
+                royalty_bps: event.royalty_bps,
+                metadata_hash: blob_hash,
+                last_sale_price: None,
+                image_url: image_url.clone(),
+                transfer_policy: transfer_policy.unwrap_or(event.transfer_policy),
+                minted_at,
+                status: TicketStatus::Valid,
+                redemption: None,
+                mint_index: event.minted_tickets,
+            };
+            self.state.tickets.insert(&ticket_id, ticket).unwrap();
+            self.reserve_seat(event_id, seat).await;
+            owned.insert(ticket_id.clone());
+            event.minted_tickets += 1;
+
+            let history = TicketHistory {
+                ownership_history: vec![OwnershipRecord {
+                    owner,
+                    owner_chain: minter_chain,
+                    acquired_at: minted_at,
+                    price_paid: None,
+                    acquisition_type: AcquisitionType::Minted,
+                }],
+                price_history: vec![],
+                check_ins: vec![],
+            };
+            self.state.ticket_history.insert(&ticket_id, history).unwrap();
+            self.track_write(PendingWrite::Ticket(ticket_id.clone()));
+            ticket_ids.push(ticket_id);
+        }
+        self.state.owned_ticket_ids.insert(&minter_chain, owned).unwrap();
+        self.state.events.insert(event_id, event).unwrap();
+        self.record_acquisition(event_id, &owner, &minter_chain, seats.len() as u32, true)
+            .await;
+
+        eprintln!(
+            "[LOCAL_MINT] {} optimistic ticket(s) stored for event '{}'",
+            ticket_ids.len(),
+            event_id.value
+        );
+        ticket_ids
     }
 
-    /// Mints a ticket (on hub where events live)
+    /// Mints a ticket (on hub where events live) and returns it
+    #[allow(clippy::too_many_arguments)]
     async fn mint_ticket(
         &mut self,
-        minter_chain: String,
-        owner: String,
+        minter_chain: ChainId,
+        owner: AccountOwner,
+        owner_signed: bool,
         event_id: EventId,
         seat: String,
         blob_hash: DataBlobHash,
         image_url: Option<String>,
-    ) {
-        let event = self
-            .state
-            .events
-            .get(&event_id)
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: Vec<MerkleHash>,
+    ) -> Ticket {
+        let ticket = self
+            .mint_seats(
+                &minter_chain,
+                &owner,
+                owner_signed,
+                &event_id,
+                vec![seat],
+                blob_hash,
+                image_url,
+                transfer_policy,
+                &presale_proof,
+            )
             .await
-            .unwrap()
-            .expect("event not found");
+            .pop()
+            .expect("no ticket minted");
 
-        assert_eq!(event.organizer_chain, minter_chain, "Only organizer can mint");
-        assert!(
-            event.minted_tickets < event.max_tickets,
-            "max tickets reached"
-        );
+        // Also send ticket to owner's chain if different from hub
+        let owner_chain = ticket.owner_chain;
+        let marketplace_chain = self.hub_chain();
+        if owner_chain != marketplace_chain {
+            self.runtime.send_message(
+                owner_chain,
+                Message::Transfer {
+                    ticket: ticket.clone(),
+                    target_chain: owner_chain,
+                    seller_chain: marketplace_chain,
+                    sale_price: None,
+                    previous_owner: ticket.owner,
+                    previous_sale_price: None,
+                    sold_listing: None,
+                },
+            );
+        }
+
+        // Emit ticket to stream for subscribers to sync
+        self.emit_event(StreamEvent::TicketMinted { ticket: ticket.clone() });
+
+        eprintln!("[MINT_TICKET] Ticket minted for event '{}' and emitted to stream", event_id.value);
+        ticket
+    }
+
+    /// Mints a batch of seats (on hub), delivers them with a single message and stream event,
+    /// and returns the tickets in seat order.
+    #[allow(clippy::too_many_arguments)]
+    async fn mint_ticket_batch(
+        &mut self,
+        minter_chain: ChainId,
+        owner: AccountOwner,
+        owner_signed: bool,
+        event_id: EventId,
+        seats: Vec<String>,
+        blob_hash: DataBlobHash,
+        image_url: Option<String>,
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: Vec<MerkleHash>,
+    ) -> Vec<Ticket> {
+        assert!(seats.len() <= MAX_MINT_BATCH, "batch exceeds {} seats", MAX_MINT_BATCH);
+        let tickets = self
+            .mint_seats(
+                &minter_chain,
+                &owner,
+                owner_signed,
+                &event_id,
+                seats,
+                blob_hash,
+                image_url,
+                transfer_policy,
+                &presale_proof,
+            )
+            .await;
+
+        // Deliver all tickets to the owner's chain in one message
+        let marketplace_chain = self.hub_chain();
+        if minter_chain != marketplace_chain {
+            self.runtime.send_message(
+                minter_chain,
+                Message::TransferBatch {
+                    tickets: tickets.clone(),
+                    target_chain: minter_chain,
+                    seller_chain: marketplace_chain,
+                },
+            );
+        }
+
+        let count = tickets.len();
+        self.emit_event(StreamEvent::TicketsMinted { tickets: tickets.clone() });
+
+        eprintln!("[MINT_BATCH] {} tickets minted for event '{}' and emitted to stream", count, event_id.value);
+        tickets
+    }
+
+    /// Validates and stores tickets for the given seats on the hub.
+    /// Capacity and seat uniqueness are checked for the whole set before anything is written.
+    /// `transfer_policy` overrides the event's default policy for these tickets (e.g. a tier);
+    /// overrides may only be stricter, so minters cannot loosen a soulbound event.
+    /// While a presale is open it replaces the sale window, admitting only allowlisted holders.
+    /// The minter chain is only the recipient's own chain when the owner signed the mint;
+    /// otherwise (a box office minting for a fan) only the owner account is checked.
+    #[allow(clippy::too_many_arguments)]
+    async fn mint_seats(
+        &mut self,
+        minter_chain: &ChainId,
+        owner: &AccountOwner,
+        owner_signed: bool,
+        event_id: &EventId,
+        seats: Vec<String>,
+        blob_hash: DataBlobHash,
+        image_url: Option<String>,
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: &[MerkleHash],
+    ) -> Vec<Ticket> {
+        let mut event = self
+            .state
+            .events
+            .get(event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+
+        assert!(
+            self.has_event_role(&event, minter_chain, EventRole::Minter).await,
+            "Only organizer or minter can mint"
+        );
+        assert!(!seats.is_empty(), "no seats to mint");
+        if let Some(policy) = transfer_policy {
+            assert!(
+                policy.is_at_least_as_strict_as(event.transfer_policy),
+                "transfer policy {:?} is looser than the event's {:?}",
+                policy,
+                event.transfer_policy
+            );
+        }
+        let now = self.now_secs();
+        let presale = self.state.presales.get(event_id).await.unwrap();
+        match presale.filter(|presale| presale.is_active(now)) {
+            Some(presale) => assert!(
+                presale.allowlist.admits(
+                    owner,
+                    owner_signed.then_some(minter_chain),
+                    presale_proof,
+                ),
+                "owner is not on the presale allowlist"
+            ),
+            None => assert!(event.is_on_sale(now), "event is not on sale"),
+        }
+        assert!(event.has_capacity_for(seats.len()), "max tickets reached");
+        let mut batch_seats = BTreeSet::new();
+        for seat in &seats {
+            assert!(
+                !self.is_seat_taken(event_id, seat).await,
+                "seat already minted"
+            );
+            if let Some(normalized) = ticketing::normalize_seat(seat) {
+                assert!(batch_seats.insert(normalized), "duplicate seat in batch");
+            }
+        }
+
+        self.assert_valid_metadata(blob_hash, &seats);
+
+        let count = seats.len() as u32;
+        self.assert_within_holder_limits(&event, owner, minter_chain, count, true).await;
+        self.record_acquisition(event_id, owner, minter_chain, count, true).await;
+
+        // Get current timestamp for minted_at
+        let minted_at = self.runtime.system_time().micros() / 1000; // Convert to ms
+        let chain_id = self.runtime.chain_id();
+        let application_id = self.runtime.application_id().forget_abi();
+
+        let owner_chain = *minter_chain;
+        let mut owned = self
+            .state
+            .owned_ticket_ids
+            .get(&owner_chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+
+        use ticketing::{TicketHistory, OwnershipRecord, AcquisitionType};
+        let mut tickets = Vec::with_capacity(seats.len());
+        for seat in seats {
+            let ticket_id = Ticket::create_ticket_id(
+                &chain_id,
+                &application_id,
+                event_id,
+                &seat,
+                minter_chain,
+                &blob_hash,
+                event.minted_tickets,
+            )
+            .expect("failed to create ticket id");
+
+            let ticket = Ticket {
+                ticket_id: ticket_id.clone(),
+                event_id: event_id.clone(),
+                event_name: event.name.clone(),
+                seat,
+                organizer_chain: event.organizer_chain,
+                owner_chain,
+                owner: *owner,
+                minter_chain: *minter_chain,
+                royalty_bps: event.royalty_bps,
+                metadata_hash: blob_hash,
+                last_sale_price: None,
+                image_url: image_url.clone(),
+                transfer_policy: transfer_policy.unwrap_or(event.transfer_policy),
+                minted_at,
+                status: TicketStatus::Valid,
+                redemption: None,
+                mint_index: event.minted_tickets,
+            };
+
+            // Store ticket on hub
+            self.state.tickets.insert(&ticket_id, ticket.clone()).unwrap();
+            self.reserve_seat(event_id, &ticket.seat).await;
+
+            // Wave 6: Create initial ownership history record
+            let ownership_record = OwnershipRecord {
+                owner: *owner,
+                owner_chain,
+                acquired_at: minted_at,
+                price_paid: None,
+                acquisition_type: AcquisitionType::Minted,
+            };
+            let history = TicketHistory {
+                ownership_history: vec![ownership_record],
+                price_history: vec![],
+                check_ins: vec![],
+            };
+            self.state.ticket_history.insert(&ticket_id, history).unwrap();
+
+            owned.insert(ticket_id);
+            event.minted_tickets += 1;
+            tickets.push(ticket);
+        }
+
+        // Track ownership on hub
+        self.state.owned_ticket_ids.insert(&owner_chain, owned).unwrap();
+
+        // Update event counter
+        self.state.events.insert(event_id, event).unwrap();
+
+        tickets
+    }
+
+    /// Current block time in Unix seconds (the unit used by event timestamps).
+    fn now_secs(&mut self) -> u64 {
+        self.runtime.system_time().micros() / 1_000_000
+    }
+
+    /// Returns true if the application trusts caller-supplied owner addresses.
+    fn is_demo_mode(&mut self) -> bool {
+        self.runtime.application_parameters().demo_mode
+    }
+
+    /// Resolves the account acting in the current operation: the block's authenticated
+    /// signer, or in demo mode the owner claimed by the operation.
+    fn caller_owner(&mut self, claimed: Option<AccountOwner>) -> AccountOwner {
+        if self.is_demo_mode() {
+            if let Some(owner) = claimed {
+                return owner;
+            }
+        }
+        let signer = self
+            .runtime
+            .authenticated_signer()
+            .expect("operation must be signed by the acting owner");
+        if let Some(owner) = claimed {
+            assert_eq!(owner, signer, "owner does not match the block signer");
+        }
+        signer
+    }
+
+    /// Panics unless secondary sales are open for the ticket's event.
+    /// Collectibles trade after the event, so the resale window does not apply to them.
+    /// Events unknown on this chain have no window to enforce (the hub re-checks).
+    async fn assert_resale_open(&mut self, ticket: &Ticket) {
+        if ticket.status == TicketStatus::Collectible {
+            return;
+        }
+        let now = self.now_secs();
+        if let Some(event) = self.state.events.get(&ticket.event_id).await.unwrap() {
+            assert!(event.is_resale_open(now), "resale is not open for this event");
+        }
+    }
+
+    /// Burns a ticket (on hub): drops it from every index, cancels its listing, optionally
+    /// frees its seat and capacity, and notifies the owner chain and stream subscribers.
+    async fn burn_ticket(
+        &mut self,
+        requester_chain: ChainId,
+        owner: AccountOwner,
+        ticket_id: TicketId,
+        reason: Option<String>,
+        release_seat: bool,
+    ) {
+        let ticket = self.get_ticket(&ticket_id).await;
+        let mut event = self
+            .state
+            .events
+            .get(&ticket.event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+        let is_holder = ticket.owner_chain == requester_chain && ticket.owner == owner;
+        let is_admin = self.has_event_role(&event, &requester_chain, EventRole::Admin).await;
+        // Releasing frees capacity for new mints, which only the organizer side may do
+        assert!(
+            !release_seat || is_admin,
+            "Only the organizer or an event admin can release the seat"
+        );
+        if !is_holder {
+            assert!(is_admin, "Only the holder or organizer can burn a ticket");
+            assert!(
+                reason.as_ref().is_some_and(|reason| !reason.trim().is_empty()),
+                "organizer burns require a reason"
+            );
+        }
+
+        // Cancel any active listing
+        if let Some(mut listing) = self.state.listings.get(&ticket_id).await.unwrap() {
+            if listing.status == ListingStatus::Active {
+                listing.status = ListingStatus::Cancelled;
+                self.state.listings.insert(&ticket_id, listing.clone()).unwrap();
+                self.emit_event(StreamEvent::ListingUpdated { listing });
+            }
+        }
+
+        self.remove_burned_ticket(&ticket_id, &ticket.owner_chain).await;
+
+        let released_seat = if release_seat {
+            self.release_seat(&ticket.event_id, &ticket.seat).await;
+            event.released_tickets += 1;
+            self.state.events.insert(&event.id, event.clone()).unwrap();
+            Some(ticket.seat.clone())
+        } else {
+            None
+        };
+
+        // Make sure the owner chain drops its copy even if it is not subscribed
+        if ticket.owner_chain != self.hub_chain() {
+            self.runtime.send_message(
+                ticket.owner_chain,
+                Message::TicketBurned { ticket_id: ticket_id.clone() },
+            );
+        }
+
+        if released_seat.is_some() {
+            self.emit_event(StreamEvent::EventUpdated { event: event.clone() });
+        }
+        self.emit_event(StreamEvent::TicketBurned {
+            ticket_id,
+            event_id: event.id.clone(),
+            owner_chain: ticket.owner_chain,
+            released_seat,
+            reason,
+        });
+
+        eprintln!("[BURN] Ticket burned for event '{}'", event.id.value);
+    }
+
+    /// Drops a burned ticket from the ticket store, ownership index and listings, and
+    /// releases its holder counts.
+    async fn remove_burned_ticket(&mut self, ticket_id: &TicketId, owner_chain: &ChainId) {
+        if let Some(ticket) = self.state.tickets.get(ticket_id).await.unwrap() {
+            self.record_release(&ticket.event_id, &ticket.owner, &ticket.owner_chain).await;
+        }
+        self.remove_owned_ticket(owner_chain, ticket_id).await;
+        self.state.tickets.remove(ticket_id).unwrap();
+        self.state.listings.remove(ticket_id).unwrap();
+        self.state.ticket_approvals.remove(ticket_id).unwrap();
+    }
+
+    /// Removes a ticket from the ownership index of `owner_chain`.
+    async fn remove_owned_ticket(&mut self, owner_chain: &ChainId, ticket_id: &TicketId) {
+        let mut owned = self
+            .state
+            .owned_ticket_ids
+            .get(owner_chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        owned.remove(ticket_id);
+        self.state.owned_ticket_ids.insert(owner_chain, owned).unwrap();
+    }
+
+    /// Adds `amount` to the pending royalties of `chain` and to the running total.
+    async fn credit_royalty(&mut self, chain: &ChainId, amount: u128) {
+        let mut balance = self
+            .state
+            .royalty_balances
+            .get(chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        balance.pending += amount;
+        self.state.royalty_balances.insert(chain, balance).unwrap();
+        let total = self.state.total_royalties.get() + amount;
+        self.state.total_royalties.set(total);
+    }
+
+    /// Takes back a royalty credited for a sale whose delivery bounced.
+    async fn debit_royalty(&mut self, chain: &ChainId, amount: u128) {
+        let mut balance = self
+            .state
+            .royalty_balances
+            .get(chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        balance.pending = balance.pending.saturating_sub(amount);
+        self.state.royalty_balances.insert(chain, balance).unwrap();
+        let total = self.state.total_royalties.get().saturating_sub(amount);
+        self.state.total_royalties.set(total);
+    }
+
+    /// Appends the new owner (and the sale price, if sold) to a ticket's history.
+    async fn record_transfer_history(
+        &mut self,
+        ticket_id: &TicketId,
+        new_owner: AccountOwner,
+        new_chain: ChainId,
+        sale_price: Option<u128>,
+        transferred_at: u64,
+    ) {
+        use ticketing::{AcquisitionType, OwnershipRecord, PriceEventType, PriceHistoryEntry};
+
+        let mut history = self
+            .state
+            .ticket_history
+            .get(ticket_id)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        let acquisition_type = if sale_price.is_some() {
+            AcquisitionType::Purchased
+        } else {
+            AcquisitionType::Transferred
+        };
+        history.ownership_history.push(OwnershipRecord {
+            owner: new_owner,
+            owner_chain: new_chain,
+            acquired_at: transferred_at,
+            price_paid: sale_price.map(|p| p.to_string()),
+            acquisition_type,
+        });
+        if let Some(price) = sale_price {
+            history.price_history.push(PriceHistoryEntry {
+                price: price.to_string(),
+                timestamp: transferred_at,
+                event_type: PriceEventType::Sold,
+            });
+        }
+        self.state.ticket_history.insert(ticket_id, history).unwrap();
+    }
+
+    /// Turns the attended (redeemed) tickets of an ended event into collectibles (on hub).
+    /// Can be re-run to pick up check-ins synced after the first conversion.
+    async fn convert_to_collectibles(
+        &mut self,
+        requester_chain: ChainId,
+        event_id: EventId,
+        mut terms: CollectibleTerms,
+    ) {
+        assert!(terms.royalty_bps <= MAX_BPS, "royalty_bps exceeds maximum");
+        assert!(
+            terms.image_url.is_some() || terms.blob_hash.is_some(),
+            "collectibles need an image or blob"
+        );
+        if let Some(blob_hash) = terms.blob_hash {
+            self.runtime.assert_data_blob_exists(blob_hash);
+            self.note_verified_blob(blob_hash);
+        }
+        let now = self.now_secs();
+        terms.converted_at = self.runtime.system_time().micros() / 1000;
+        let mut event = self
+            .state
+            .events
+            .get(&event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+        assert!(
+            self.has_event_role(&event, &requester_chain, EventRole::Admin).await,
+            "Only organizer or admin can convert collectibles"
+        );
+        assert!(event.has_ended(now), "event has not ended yet");
+
+        let redeemed = self
+            .state
+            .redeemed_tickets
+            .get(&event_id)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        let mut ticket_ids = Vec::new();
+        for ticket_id in redeemed {
+            if let Some(ticket) = self.state.tickets.get(&ticket_id).await.unwrap() {
+                if ticket.status == TicketStatus::Redeemed {
+                    self.apply_collectible(&ticket_id, &terms).await;
+                    ticket_ids.push(ticket_id);
+                }
+            }
+        }
+
+        event.collectible = Some(terms.clone());
+        self.state.events.insert(&event_id, event.clone()).unwrap();
+        self.emit_event(StreamEvent::EventUpdated { event });
+        let count = ticket_ids.len();
+        self.emit_event(StreamEvent::CollectiblesConverted { event_id: event_id.clone(), terms, ticket_ids });
+
+        eprintln!("[COLLECTIBLE] {} tickets of event '{}' converted", count, event_id.value);
+    }
+
+    /// Switches a local redeemed ticket to the collectible state, art and royalty terms.
+    async fn apply_collectible(&mut self, ticket_id: &TicketId, terms: &CollectibleTerms) {
+        if let Some(mut ticket) = self.state.tickets.get(ticket_id).await.unwrap() {
+            ticket.status = TicketStatus::Collectible;
+            ticket.royalty_bps = terms.royalty_bps;
+            if terms.image_url.is_some() {
+                ticket.image_url = terms.image_url.clone();
+            }
+            if let Some(blob_hash) = terms.blob_hash {
+                ticket.metadata_hash = blob_hash;
+                self.note_verified_blob(blob_hash);
+            }
+            self.state.tickets.insert(ticket_id, ticket).unwrap();
+        }
+    }
+
+    /// Checks a ticket in at the venue right now (on hub).
+    async fn redeem_ticket(&mut self, scanner_chain: ChainId, ticket_id: TicketId, gate: String) {
+        let ticket = self.get_ticket(&ticket_id).await;
+        let event = self
+            .state
+            .events
+            .get(&ticket.event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+        assert!(
+            self.has_event_role(&event, &scanner_chain, EventRole::Scanner).await,
+            "Only organizer or scanner can redeem"
+        );
+        assert_eq!(ticket.status, TicketStatus::Valid, "ticket already redeemed");
+
+        let redemption = Redemption {
+            redeemed_at: self.runtime.system_time().micros() / 1000,
+            gate,
+            scanner_chain,
+        };
+        self.finalize_redemption(ticket_id, redemption).await;
+
+        eprintln!("[REDEEM] Ticket redeemed for event '{}'", event.id.value);
+    }
+
+    /// Applies a batch of offline check-ins from a scanner chain (on hub).
+    /// Conflicts resolve deterministically: the earliest scan wins, and every other scan
+    /// of the same ticket is recorded as a fraud alert.
+    async fn submit_check_ins(
+        &mut self,
+        scanner_chain: ChainId,
+        event_id: EventId,
+        mut check_ins: Vec<CheckIn>,
+    ) {
+        let event = self
+            .state
+            .events
+            .get(&event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+        assert!(
+            self.has_event_role(&event, &scanner_chain, EventRole::Scanner).await,
+            "Only organizer or scanner can submit check-ins"
+        );
+
+        // Process in scan order so results do not depend on how the batch was assembled
+        check_ins.sort_by(|a, b| {
+            (a.scanned_at, &a.ticket_id, &a.gate).cmp(&(b.scanned_at, &b.ticket_id, &b.gate))
+        });
+        let detected_at = self.runtime.system_time().micros() / 1000;
+        let count = check_ins.len();
+
+        for check_in in check_ins {
+            let Some(ticket) = self.state.tickets.get(&check_in.ticket_id).await.unwrap() else {
+                eprintln!("[CHECK_IN] Unknown ticket in batch, skipping");
+                continue;
+            };
+            if ticket.event_id != event_id {
+                eprintln!("[CHECK_IN] Ticket belongs to another event, skipping");
+                continue;
+            }
+            let redemption = Redemption {
+                redeemed_at: check_in.scanned_at,
+                gate: check_in.gate,
+                scanner_chain,
+            };
+            match ticket.redemption {
+                None => self.finalize_redemption(check_in.ticket_id, redemption).await,
+                // Same scan submitted again (e.g. a retried batch)
+                Some(existing) if existing == redemption => {}
+                Some(existing) => {
+                    let (accepted, rejected) = if redemption.redeemed_at < existing.redeemed_at {
+                        self.finalize_redemption(check_in.ticket_id.clone(), redemption.clone())
+                            .await;
+                        (redemption, existing)
+                    } else {
+                        (existing, redemption)
+                    };
+                    self.raise_fraud_alert(FraudAlert {
+                        ticket_id: check_in.ticket_id,
+                        event_id: event_id.clone(),
+                        accepted,
+                        rejected,
+                        detected_at,
+                    })
+                    .await;
+                }
+            }
+        }
+
+        eprintln!("[CHECK_IN] Processed {} check-ins for event '{}'", count, event_id.value);
+    }
+
+    /// Stores a fraud alert (ignoring repeats of the same rejected scan) and emits it.
+    async fn raise_fraud_alert(&mut self, alert: FraudAlert) {
+        let mut alerts = self
+            .state
+            .fraud_alerts
+            .get(&alert.event_id)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        if alerts
+            .iter()
+            .any(|known| known.ticket_id == alert.ticket_id && known.rejected == alert.rejected)
+        {
+            return;
+        }
+        alerts.push(alert.clone());
+        self.state.fraud_alerts.insert(&alert.event_id, alerts).unwrap();
+
+        self.emit_event(StreamEvent::FraudAlertRaised { alert });
+        eprintln!("[FRAUD_ALERT] Duplicate check-in detected");
+    }
+
+    /// Records a redemption on the hub: freezes the ticket, cancels any active listing
+    /// and emits the redemption so owner chains freeze their copy too.
+    async fn finalize_redemption(&mut self, ticket_id: TicketId, redemption: Redemption) {
+        self.apply_redemption(&ticket_id, redemption.clone()).await;
+
+        // A redeemed ticket can no longer be sold
+        if let Some(mut listing) = self.state.listings.get(&ticket_id).await.unwrap() {
+            if listing.status == ListingStatus::Active {
+                listing.status = ListingStatus::Cancelled;
+                self.state.listings.insert(&ticket_id, listing.clone()).unwrap();
+                self.emit_event(StreamEvent::ListingUpdated { listing });
+            }
+        }
+
+        self.emit_event(StreamEvent::TicketRedeemed { ticket_id, redemption });
+    }
+
+    /// Marks a local ticket copy as redeemed and records the check-in in its history.
+    async fn apply_redemption(&mut self, ticket_id: &TicketId, redemption: Redemption) {
+        if let Some(mut ticket) = self.state.tickets.get(ticket_id).await.unwrap() {
+            let mut redeemed = self
+                .state
+                .redeemed_tickets
+                .get(&ticket.event_id)
+                .await
+                .unwrap()
+                .unwrap_or_default();
+            redeemed.insert(ticket_id.clone());
+            self.state.redeemed_tickets.insert(&ticket.event_id, redeemed).unwrap();
+
+            // A ticket already converted to a collectible keeps that status
+            if ticket.status == TicketStatus::Valid {
+                ticket.status = TicketStatus::Redeemed;
+            }
+            ticket.redemption = Some(redemption.clone());
+            self.state.tickets.insert(ticket_id, ticket).unwrap();
+        }
+        let mut history = self
+            .state
+            .ticket_history
+            .get(ticket_id)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        history.check_ins.push(redemption);
+        self.state.ticket_history.insert(ticket_id, history).unwrap();
+    }
+
+    /// Returns true if the chain may act with `role` on the event.
+    /// The organizer holds every role and event admins inherit all non-admin roles.
+    async fn has_event_role(&self, event: &Event, chain: &ChainId, role: EventRole) -> bool {
+        if event.organizer_chain == *chain {
+            return true;
+        }
+        let Some(roles) = self
+            .state
+            .event_roles
+            .get(&event.id)
+            .await
+            .unwrap()
+            .and_then(|mut roles| roles.remove(chain))
+        else {
+            return false;
+        };
+        ticketing::roles_grant(&roles, role)
+    }
+
+    /// Updates an event's per-holder caps (on hub) and emits the updated event.
+    async fn set_holder_limits(
+        &mut self,
+        requester_chain: ChainId,
+        event_id: EventId,
+        max_per_holder: Option<u32>,
+        max_purchases_per_holder: Option<u32>,
+    ) {
+        self.update_event(&requester_chain, &event_id, |event| {
+            event.max_per_holder = max_per_holder;
+            event.max_purchases_per_holder = max_purchases_per_holder;
+        })
+        .await;
+        eprintln!("[HOLDER_LIMITS] Limits updated for event '{}'", event_id.value);
+    }
+
+    /// Sets or clears an event's presale (on hub) and emits it to subscribers.
+    async fn set_presale(&mut self, requester_chain: ChainId, event_id: EventId, presale: Option<Presale>) {
+        self.assert_event_admin(&requester_chain, &event_id).await;
+        match presale {
+            Some(presale) => {
+                assert!(
+                    presale.starts_at.is_none_or(|start| start < presale.ends_at),
+                    "presale must start before it ends"
+                );
+                self.state.presales.insert(&event_id, presale.clone()).unwrap();
+                self.emit_presale(event_id, Some(presale));
+            }
+            None => {
+                self.state.presales.remove(&event_id).unwrap();
+                self.emit_presale(event_id, None);
+            }
+        }
+    }
+
+    /// Edits the entries of an event's full presale allowlist (on hub).
+    async fn update_presale_allowlist(
+        &mut self,
+        requester_chain: ChainId,
+        event_id: EventId,
+        added: Vec<HolderKey>,
+        removed: Vec<HolderKey>,
+    ) {
+        self.assert_event_admin(&requester_chain, &event_id).await;
+        let mut presale = self
+            .state
+            .presales
+            .get(&event_id)
+            .await
+            .unwrap()
+            .expect("event has no presale");
+        let Allowlist::Full(entries) = &mut presale.allowlist else {
+            panic!("only full allowlists can be edited; set a new Merkle root instead");
+        };
+        entries.extend(added);
+        for key in &removed {
+            entries.remove(key);
+        }
+        self.state.presales.insert(&event_id, presale.clone()).unwrap();
+        self.emit_presale(event_id, Some(presale));
+    }
+
+    /// Panics unless the chain is the event's organizer or an event admin.
+    async fn assert_event_admin(&self, chain: &ChainId, event_id: &EventId) {
+        let event = self
+            .state
+            .events
+            .get(event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+        assert!(
+            self.has_event_role(&event, chain, EventRole::Admin).await,
+            "Only the organizer or an event admin can manage the presale"
+        );
+    }
+
+    fn emit_presale(&mut self, event_id: EventId, presale: Option<Presale>) {
+        eprintln!("[PRESALE] Presale updated for event '{}'", event_id.value);
+        self.emit_event(StreamEvent::PresaleUpdated { event_id, presale });
+    }
+
+    /// Updates an event's transfer lockout (on hub) and emits the updated event.
+    async fn set_transfer_lockout(
+        &mut self,
+        requester_chain: ChainId,
+        event_id: EventId,
+        lockout_secs: Option<u64>,
+    ) {
+        self.update_event(&requester_chain, &event_id, |event| {
+            event.transfer_lockout_secs = lockout_secs;
+        })
+        .await;
+        eprintln!("[TRANSFER_LOCKOUT] Lockout updated for event '{}'", event_id.value);
+    }
+
+    /// Applies an organizer/admin change to an event (on hub) and emits the updated event.
+    async fn update_event(
+        &mut self,
+        requester_chain: &ChainId,
+        event_id: &EventId,
+        update: impl FnOnce(&mut Event),
+    ) {
+        let mut event = self
+            .state
+            .events
+            .get(event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+        assert!(
+            self.has_event_role(&event, requester_chain, EventRole::Admin).await,
+            "Only organizer or admin can change event settings"
+        );
+        update(&mut event);
+        self.state.events.insert(event_id, event.clone()).unwrap();
+
+        self.emit_event(StreamEvent::EventUpdated { event });
+    }
+
+    /// Panics unless the ticket may change hands right now: it must not be redeemed (unless
+    /// converted to a collectible) and its event must not be inside the pre-show transfer lockout.
+    /// Events unknown on this chain have no lockout to enforce (the hub re-checks).
+    async fn assert_transferable(&mut self, ticket: &Ticket) {
+        match ticket.status {
+            TicketStatus::Valid => {}
+            // The event is over, so the pre-show lockout no longer applies
+            TicketStatus::Collectible => return,
+            TicketStatus::Redeemed => panic!("ticket already redeemed"),
+        }
+        let now = self.now_secs();
+        if let Some(event) = self.state.events.get(&ticket.event_id).await.unwrap() {
+            if let Some(unlocks_at) = event.transfer_locked_until(now) {
+                panic!("transfers are locked for this event until {}", unlocks_at);
+            }
+        }
+    }
+
+    /// Panics if `owner`/`owner_chain` acquiring `count` more tickets would exceed the event's caps.
+    /// Staff chains (those with the minter role) hold unsold inventory and the shared hub
+    /// chain hosts many wallets, so only the owner address is capped on those.
+    async fn assert_within_holder_limits(
+        &self,
+        event: &Event,
+        owner: &AccountOwner,
+        owner_chain: &ChainId,
+        count: u32,
+        is_purchase: bool,
+    ) {
+        if !event.has_holder_limits() {
+            return;
+        }
+        let is_shared_chain = *self.state.marketplace_chain.get() == Some(*owner_chain)
+            || self.has_event_role(event, owner_chain, EventRole::Minter).await;
+        let [owner_key, chain_key] = ticketing::holder_keys(owner, owner_chain);
+        let keys = if is_shared_chain { vec![owner_key] } else { vec![owner_key, chain_key] };
+
+        for key in keys {
+            let key = (event.id.clone(), key);
+            if let Some(max) = event.max_per_holder {
+                let held = self.state.holdings.get(&key).await.unwrap().unwrap_or(0);
+                assert!(
+                    held + count <= max,
+                    "holding limit of {} tickets per holder reached for event '{}'",
+                    max,
+                    event.id.value
+                );
+            }
+            if let (true, Some(max)) = (is_purchase, event.max_purchases_per_holder) {
+                let bought = self.state.purchases.get(&key).await.unwrap().unwrap_or(0);
+                assert!(
+                    bought + count <= max,
+                    "purchase limit of {} tickets per holder reached for event '{}'",
+                    max,
+                    event.id.value
+                );
+            }
+        }
+    }
+
+    /// Counts `count` tickets of the event as acquired by `owner`/`owner_chain`.
+    async fn record_acquisition(
+        &mut self,
+        event_id: &EventId,
+        owner: &AccountOwner,
+        owner_chain: &ChainId,
+        count: u32,
+        is_purchase: bool,
+    ) {
+        for key in ticketing::holder_keys(owner, owner_chain) {
+            let key = (event_id.clone(), key);
+            let held = self.state.holdings.get(&key).await.unwrap().unwrap_or(0);
+            self.state.holdings.insert(&key, held + count).unwrap();
+            if is_purchase {
+                let bought = self.state.purchases.get(&key).await.unwrap().unwrap_or(0);
+                self.state.purchases.insert(&key, bought + count).unwrap();
+            }
+        }
+    }
+
+    /// Counts one ticket of the event as no longer held by `owner`/`owner_chain`.
+    async fn record_release(&mut self, event_id: &EventId, owner: &AccountOwner, owner_chain: &ChainId) {
+        for key in ticketing::holder_keys(owner, owner_chain) {
+            let key = (event_id.clone(), key);
+            match self.state.holdings.get(&key).await.unwrap().unwrap_or(0) {
+                0 | 1 => self.state.holdings.remove(&key).unwrap(),
+                held => self.state.holdings.insert(&key, held - 1).unwrap(),
+            }
+        }
+    }
+
+    /// Grants or revokes an event role (on hub) and emits the new role set.
+    /// Only the organizer manages admins; admins may manage the other roles.
+    async fn set_event_role(
+        &mut self,
+        granter_chain: ChainId,
+        event_id: EventId,
+        chain: ChainId,
+        role: EventRole,
+        granted: bool,
+    ) {
+        let event = self
+            .state
+            .events
+            .get(&event_id)
+            .await
+            .unwrap()
+            .expect("event not found");
+        if role == EventRole::Admin {
+            assert_eq!(event.organizer_chain, granter_chain, "Only organizer can manage admins");
+        } else {
+            assert!(
+                self.has_event_role(&event, &granter_chain, EventRole::Admin).await,
+                "Only organizer or admin can manage roles"
+            );
+        }
+
+        let mut roles = self
+            .state
+            .event_roles
+            .get(&event_id)
+            .await
+            .unwrap()
+            .unwrap_or_default()
+            .remove(&chain)
+            .unwrap_or_default();
+        if granted {
+            roles.insert(role);
+        } else {
+            roles.remove(&role);
+        }
+        let roles: Vec<EventRole> = roles.into_iter().collect();
+        self.store_event_roles(&event_id, chain, roles.clone()).await;
+
+        self.emit_event(StreamEvent::EventRolesUpdated { event_id: event_id.clone(), chain, roles });
+
+        eprintln!("[EVENT_ROLE] {:?} {} for event '{}'", role, if granted { "granted" } else { "revoked" }, event_id.value);
+    }
+
+    /// Replaces the roles a chain holds for an event (dropping the entry when empty).
+    async fn store_event_roles(&mut self, event_id: &EventId, chain: ChainId, roles: Vec<EventRole>) {
+        let mut event_roles = self
+            .state
+            .event_roles
+            .get(event_id)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        if roles.is_empty() {
+            event_roles.remove(&chain);
+        } else {
+            event_roles.insert(chain, roles.into_iter().collect());
+        }
+        self.state.event_roles.insert(event_id, event_roles).unwrap();
+    }
 
-        let ticket_id = Ticket::create_ticket_id(
-            &self.runtime.chain_id(),
-            &self.runtime.application_id().forget_abi(),
-            &event_id,
-            &seat,
-            &minter_chain,
-            &blob_hash,
-            event.minted_tickets,
-        )
-        .expect("failed to create ticket id");
+    /// Asserts that a metadata blob is published and follows the `TicketMetadata` schema.
+    /// The all-zero placeholder hash (no metadata) is only accepted in demo mode.
+    fn assert_valid_metadata(&mut self, blob_hash: DataBlobHash, seats: &[String]) {
+        if ticketing::is_placeholder_blob(&blob_hash) {
+            assert!(self.is_demo_mode(), "tickets need a published metadata blob");
+            return;
+        }
+        // Reading the blob fails the transaction if it was never published
+        let bytes = self.runtime.read_data_blob(blob_hash);
+        let metadata = TicketMetadata::parse(&bytes).unwrap_or_else(|error| panic!("{}", error));
+        if let [seat] = seats {
+            assert!(metadata.matches_seat(seat), "metadata seat does not match ticket seat");
+        }
+        self.note_verified_blob(blob_hash);
+    }
 
-        // Get current timestamp for minted_at
-        let minted_at = self.runtime.system_time().micros() / 1000; // Convert to ms
+    /// Records a metadata blob as published so the service may read it.
+    fn note_verified_blob(&mut self, blob_hash: DataBlobHash) {
+        if !ticketing::is_placeholder_blob(&blob_hash) {
+            self.state.verified_blobs.insert(&blob_hash).unwrap();
+        }
+    }
 
-        let owner_chain = minter_chain.clone();
-        let ticket = Ticket {
-            ticket_id: ticket_id.clone(),
-            event_id: event_id.clone(),
-            event_name: event.name.clone(),
-            seat,
-            organizer_chain: event.organizer_chain.clone(),
-            owner_chain: owner_chain.clone(),
-            owner: owner.clone(),
-            minter_chain: minter_chain.clone(),
-            royalty_bps: event.royalty_bps,
-            metadata_hash: blob_hash,
-            last_sale_price: None,
-            image_url,
-            minted_at,
+    /// Returns true if a reserved seat has already been minted for the event.
+    async fn is_seat_taken(&self, event_id: &EventId, seat: &str) -> bool {
+        let Some(seat) = ticketing::normalize_seat(seat) else {
+            return false;
         };
+        self.state
+            .taken_seats
+            .get(event_id)
+            .await
+            .unwrap()
+            .is_some_and(|seats| seats.contains(&seat))
+    }
 
-        // Store ticket on hub
-        self.state.tickets.insert(&ticket_id, ticket.clone()).unwrap();
-
-        // Wave 6: Create initial ownership history record
-        use ticketing::{TicketHistory, OwnershipRecord, AcquisitionType};
-        let ownership_record = OwnershipRecord {
-            owner: owner.clone(),
-            owner_chain: owner_chain.clone(),
-            acquired_at: minted_at,
-            price_paid: None,
-            acquisition_type: AcquisitionType::Minted,
-        };
-        let history = TicketHistory {
-            ownership_history: vec![ownership_record],
-            price_history: vec![],
+    /// Marks a seat as taken for the event (no-op for general admission).
+    async fn reserve_seat(&mut self, event_id: &EventId, seat: &str) {
+        let Some(seat) = ticketing::normalize_seat(seat) else {
+            return;
         };
-        self.state.ticket_history.insert(&ticket_id, history).unwrap();
-
-        // Track ownership on hub
-        let mut owned = self
+        let mut seats = self
             .state
-            .owned_ticket_ids
-            .get(&owner_chain)
+            .taken_seats
+            .get(event_id)
             .await
             .unwrap()
             .unwrap_or_default();
-        owned.insert(ticket_id.clone());
-        self.state.owned_ticket_ids.insert(&owner_chain, owned).unwrap();
+        seats.insert(seat);
+        self.state.taken_seats.insert(event_id, seats).unwrap();
+    }
 
-        // Update event counter
-        let mut updated_event = event;
-        updated_event.minted_tickets += 1;
-        self.state.events.insert(&event_id, updated_event).unwrap();
+    /// Gives a reserved seat back to the event so it can be minted again.
+    async fn release_seat(&mut self, event_id: &EventId, seat: &str) {
+        let Some(seat) = ticketing::normalize_seat(seat) else {
+            return;
+        };
+        let mut seats = self
+            .state
+            .taken_seats
+            .get(event_id)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        seats.remove(&seat);
+        self.state.taken_seats.insert(event_id, seats).unwrap();
+    }
 
-        // Also send ticket to owner's chain if different from hub
-        let marketplace_chain = self.state.marketplace_chain.get().clone();
-        if owner_chain != marketplace_chain {
-            if let Ok(owner_chain_id) = owner_chain.parse::<ChainId>() {
-                self.runtime.send_message(
-                    owner_chain_id,
-                    Message::Transfer {
-                        ticket: ticket.clone(),
-                        target_chain: owner_chain.clone(),
-                        seller_chain: marketplace_chain,
-                        sale_price: None,
-                    },
-                );
+    /// Sets or clears the operator approved for a single ticket (ticket owner only).
+    async fn approve(
+        &mut self,
+        requester_chain: ChainId,
+        owner: AccountOwner,
+        ticket_id: TicketId,
+        operator: Option<AccountOwner>,
+    ) {
+        let ticket = self.get_ticket(&ticket_id).await;
+        assert_eq!(ticket.owner_chain, requester_chain, "Not the ticket owner chain");
+        assert_eq!(ticket.owner, owner, "Not the ticket owner");
+        match operator {
+            Some(operator) => {
+                assert!(operator != owner, "cannot approve the ticket owner");
+                self.state
+                    .ticket_approvals
+                    .insert(&ticket_id, TicketApproval { owner, operator })
+                    .unwrap();
             }
+            None => self.state.ticket_approvals.remove(&ticket_id).unwrap(),
         }
+    }
 
-        // Emit ticket to stream for subscribers to sync
-        self.runtime.emit(
-            MARKETPLACE_STREAM.into(),
-            &StreamEvent::TicketMinted { ticket },
-        );
+    /// Adds or removes an operator for all tickets `owner` holds on `owner_chain`.
+    async fn set_approval_for_all(
+        &mut self,
+        owner_chain: ChainId,
+        owner: AccountOwner,
+        operator: AccountOwner,
+        approved: bool,
+    ) {
+        assert!(operator != owner, "cannot approve yourself as operator");
+        let key = (owner_chain, owner);
+        let mut operators = self
+            .state
+            .operator_approvals
+            .get(&key)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        if approved {
+            operators.insert(operator);
+        } else {
+            operators.remove(&operator);
+        }
+        if operators.is_empty() {
+            self.state.operator_approvals.remove(&key).unwrap();
+        } else {
+            self.state.operator_approvals.insert(&key, operators).unwrap();
+        }
+    }
 
-        eprintln!("[MINT_TICKET] Ticket minted for event '{}' and emitted to stream", event_id.value);
+    /// Returns true if `spender` is approved for this ticket or for all tickets its owner holds
+    /// on the owner chain. Per-ticket approvals granted by a previous owner are ignored.
+    async fn is_approved_operator(&self, ticket: &Ticket, spender: &AccountOwner) -> bool {
+        let approved_for_ticket = self
+            .state
+            .ticket_approvals
+            .get(&ticket.ticket_id)
+            .await
+            .unwrap()
+            .is_some_and(|approval| approval.owner == ticket.owner && approval.operator == *spender);
+        approved_for_ticket
+            || self
+                .state
+                .operator_approvals
+                .get(&(ticket.owner_chain, ticket.owner))
+                .await
+                .unwrap()
+                .is_some_and(|operators| operators.contains(spender))
+    }
+
+    /// Panics unless `spender` is the ticket owner or an approved operator, acting from
+    /// the owner chain.
+    async fn assert_owner_or_operator(&self, ticket: &Ticket, spender_chain: ChainId, spender: &AccountOwner) {
+        assert_eq!(ticket.owner_chain, spender_chain, "Not the ticket owner chain");
+        if *spender != ticket.owner {
+            assert!(
+                self.is_approved_operator(ticket, spender).await,
+                "Not the ticket owner or an approved operator"
+            );
+        }
     }
 
     /// Returns the ticket with the given ID.
@@ -715,100 +2904,75 @@ impl TicketingContract {
     }
 
     /// Transfers a ticket to another chain, distributing royalties if applicable.
+    /// `through_marketplace` is set for listing purchases, the only way resale-only tickets move.
     async fn transfer(
         &mut self,
         ticket: Ticket,
-        seller_chain: String,
-        buyer_chain: String,
-        new_owner: String,
+        seller_chain: ChainId,
+        buyer_chain: ChainId,
+        new_owner: AccountOwner,
         sale_price: Option<u128>,
+        through_marketplace: bool,
     ) {
-        // Remove from seller's ownership
-        let mut seller_owned = self
-            .state
-            .owned_ticket_ids
-            .get(&seller_chain)
-            .await
-            .unwrap()
-            .unwrap_or_default();
-        seller_owned.remove(&ticket.ticket_id);
-        self.state.owned_ticket_ids.insert(&seller_chain, seller_owned).unwrap();
-
-        // Handle royalty distribution if there's a sale price
-        if let Some(price) = sale_price {
-            let royalty = (price * ticket.royalty_bps as u128) / MAX_BPS as u128;
-            if royalty > 0 {
-                let mut organizer_balance = self
-                    .state
-                    .royalty_balances
-                    .get(&ticket.organizer_chain)
-                    .await
-                    .unwrap()
-                    .unwrap_or_default();
-                organizer_balance.pending += royalty;
-                self.state
-                    .royalty_balances
-                    .insert(&ticket.organizer_chain, organizer_balance)
-                    .unwrap();
+        self.assert_transferable(&ticket).await;
+        assert!(
+            ticket.transfer_policy.allows_transfer(through_marketplace),
+            "ticket transfer policy {:?} forbids this transfer",
+            ticket.transfer_policy
+        );
 
-                let total = self.state.total_royalties.get() + royalty;
-                self.state.total_royalties.set(total);
+        // Per-holder caps are enforced on the hub, against the counters it maintains. User chains
+        // only count locally, so tickets of capped events change hands through the hub
+        // (events unknown here are not checked)
+        let is_hub = self.runtime.chain_id() == self.hub_chain();
+        if let Some(event) = self.state.events.get(&ticket.event_id).await.unwrap() {
+            if is_hub {
+                self.assert_within_holder_limits(&event, &new_owner, &buyer_chain, 1, sale_price.is_some())
+                    .await;
+            } else {
+                assert!(
+                    !event.has_holder_limits(),
+                    "tickets of events with holder limits change hands through the marketplace"
+                );
             }
         }
+        self.record_release(&ticket.event_id, &ticket.owner, &ticket.owner_chain).await;
+        self.record_acquisition(&ticket.event_id, &new_owner, &buyer_chain, 1, sale_price.is_some())
+            .await;
+
+        // Remove from seller's ownership
+        self.remove_owned_ticket(&seller_chain, &ticket.ticket_id).await;
 
-        // Cancel any existing listing
-        if self.state.listings.get(&ticket.ticket_id).await.unwrap().is_some() {
-            self.state.listings.remove(&ticket.ticket_id).unwrap();
+        // Handle royalty distribution if there's a sale price
+        let royalty = sale_royalty(&ticket, sale_price);
+        if royalty > 0 {
+            self.credit_royalty(&ticket.organizer_chain, royalty).await;
         }
 
+        // Cancel any existing listing; a sold one travels with the ticket so a bounced
+        // delivery can reopen it
+        let sold_listing = match self.state.listings.get(&ticket.ticket_id).await.unwrap() {
+            Some(listing) => {
+                self.state.listings.remove(&ticket.ticket_id).unwrap();
+                (listing.status == ListingStatus::Sold).then_some(listing)
+            }
+            None => None,
+        };
+        // Approvals are granted by an owner and never pass to the next one
+        self.state.ticket_approvals.remove(&ticket.ticket_id).unwrap();
+
         // Wave 6: Record ownership transfer in history
         let transfer_time = self.runtime.system_time().micros() / 1000; // Convert to ms
-        use ticketing::{TicketHistory, OwnershipRecord, AcquisitionType, PriceHistoryEntry, PriceEventType};
-        
-        let mut history = self.state.ticket_history
-            .get(&ticket.ticket_id)
-            .await
-            .unwrap()
-            .unwrap_or_default();
-        
-        // Add new ownership record
-        let acquisition_type = if sale_price.is_some() {
-            AcquisitionType::Purchased
-        } else {
-            AcquisitionType::Transferred
-        };
-        let ownership_record = OwnershipRecord {
-            owner: new_owner.clone(),
-            owner_chain: buyer_chain.clone(),
-            acquired_at: transfer_time,
-            price_paid: sale_price.map(|p| p.to_string()),
-            acquisition_type,
-        };
-        history.ownership_history.push(ownership_record);
-        
-        // Add price history entry if this was a sale
-        if let Some(price) = sale_price {
-            let price_entry = PriceHistoryEntry {
-                price: price.to_string(),
-                timestamp: transfer_time,
-                event_type: PriceEventType::Sold,
-            };
-            history.price_history.push(price_entry);
-        }
-        
-        self.state.ticket_history.insert(&ticket.ticket_id, history).unwrap();
+        self.record_transfer_history(&ticket.ticket_id, new_owner, buyer_chain, sale_price, transfer_time)
+            .await;
 
         // Create updated ticket with new owner
         let mut updated_ticket = ticket.clone();
-        updated_ticket.owner_chain = buyer_chain.clone();
-        updated_ticket.owner = new_owner.clone();
+        updated_ticket.owner_chain = buyer_chain;
+        updated_ticket.owner = new_owner;
         updated_ticket.last_sale_price = sale_price;
 
-        // Check if we're on the hub - keep updated ticket reference for ticketsByOwner query
-        let marketplace_chain = self.state.marketplace_chain.get().clone();
-        let current_chain = self.runtime.chain_id().to_string();
-        let is_hub = current_chain == marketplace_chain;
-        
+        // On the hub, keep the updated ticket reference for the ticketsByOwner query
         if is_hub {
             // On hub: update ticket in place (don't remove) so ticketsByOwner can find it
             self.state.tickets.insert(&ticket.ticket_id, updated_ticket.clone()).unwrap();
@@ -824,11 +2988,21 @@ impl TicketingContract {
             buyer_owned.insert(ticket.ticket_id.clone());
             self.state.owned_ticket_ids.insert(&buyer_chain, buyer_owned).unwrap();
             
-            // Emit updated ticket to stream so other chains can sync
-            self.runtime.emit(
-                MARKETPLACE_STREAM.into(),
-                &StreamEvent::TicketMinted { ticket: updated_ticket.clone() },
-            );
+            // Emit the transfer so subscribers can move their ownership indexes
+            self.emit_event(StreamEvent::TicketTransferred {
+                ticket: updated_ticket.clone(),
+                previous_owner: ticket.owner,
+                previous_chain: seller_chain,
+                sale_price,
+                transferred_at: transfer_time,
+            });
+            if royalty > 0 {
+                self.emit_event(StreamEvent::RoyaltyAccrued {
+                    organizer_chain: ticket.organizer_chain,
+                    ticket_id: ticket.ticket_id.clone(),
+                    amount: royalty,
+                });
+            }
             
             eprintln!("[TRANSFER] Hub updated ticket ownership: {} -> {}", ticket.owner, new_owner);
         } else {
@@ -836,28 +3010,133 @@ impl TicketingContract {
             self.state.tickets.remove(&ticket.ticket_id).unwrap();
         }
 
-        // Parse target chain and send message
-        if let Ok(target_chain_id) = buyer_chain.parse::<ChainId>() {
-            self.runtime.send_message(
-                target_chain_id,
-                Message::Transfer {
-                    ticket: updated_ticket,
-                    target_chain: buyer_chain,
-                    seller_chain,
-                    sale_price,
-                },
-            );
+        // Tracked so a rejected delivery bounces back and the ticket is not lost
+        self.runtime
+            .prepare_message(Message::Transfer {
+                ticket: updated_ticket,
+                target_chain: buyer_chain,
+                seller_chain,
+                sale_price,
+                previous_owner: ticket.owner,
+                previous_sale_price: ticket.last_sale_price,
+                sold_listing,
+            })
+            .with_tracking()
+            .send_to(buyer_chain);
+    }
+
+    /// Gives a ticket whose transfer bounced back to its previous owner, takes back the sale's
+    /// royalty and records the return in its history. The hub also emits the return and the
+    /// royalty correction so subscribers follow, and reopens the listing the sale closed.
+    async fn restore_bounced_transfer(
+        &mut self,
+        ticket: Ticket,
+        seller_chain: ChainId,
+        sale_price: Option<u128>,
+        previous_owner: AccountOwner,
+        previous_sale_price: Option<u128>,
+        sold_listing: Option<Listing>,
+    ) {
+        use ticketing::{AcquisitionType, OwnershipRecord, PriceEventType};
+
+        let ticket_id = ticket.ticket_id.clone();
+        let mut restored = ticket.clone();
+        restored.owner = previous_owner;
+        restored.owner_chain = seller_chain;
+        restored.last_sale_price = previous_sale_price;
+
+        // Other chains dropped their copy when the ticket left; the hub kept it
+        if self.state.tickets.get(&ticket_id).await.unwrap().is_none() {
+            self.record_release(&ticket.event_id, &ticket.owner, &ticket.owner_chain).await;
+        }
+        self.sync_ticket(restored.clone(), false).await;
+
+        // The sale never completed, so neither did its royalty
+        let royalty = sale_royalty(&ticket, sale_price);
+        if royalty > 0 {
+            self.debit_royalty(&ticket.organizer_chain, royalty).await;
+        }
+
+        let returned_at = self.runtime.system_time().micros() / 1000;
+        let mut history = self
+            .state
+            .ticket_history
+            .get(&ticket_id)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        if sale_price.is_some() {
+            // The sale never completed
+            if let Some(position) = history
+                .price_history
+                .iter()
+                .rposition(|entry| entry.event_type == PriceEventType::Sold)
+            {
+                history.price_history.remove(position);
+            }
+        }
+        history.ownership_history.push(OwnershipRecord {
+            owner: previous_owner,
+            owner_chain: seller_chain,
+            acquired_at: returned_at,
+            price_paid: None,
+            acquisition_type: AcquisitionType::Returned,
+        });
+        self.state.ticket_history.insert(&ticket_id, history).unwrap();
+
+        if self.runtime.chain_id() == self.hub_chain() {
+            self.emit_event(StreamEvent::TicketTransferred {
+                ticket: restored,
+                previous_owner: ticket.owner,
+                previous_chain: ticket.owner_chain,
+                sale_price: None,
+                transferred_at: returned_at,
+            });
+            if royalty > 0 {
+                self.emit_event(StreamEvent::RoyaltyReversed {
+                    organizer_chain: ticket.organizer_chain,
+                    ticket_id: ticket_id.clone(),
+                    amount: royalty,
+                });
+            }
+            if let Some(mut listing) = sold_listing {
+                listing.status = ListingStatus::Active;
+                self.state.listings.insert(&ticket_id, listing.clone()).unwrap();
+                self.emit_event(StreamEvent::ListingUpdated { listing });
+            }
         }
+        eprintln!(
+            "[BOUNCE] Transfer to {} rejected, ticket returned to {}",
+            ticket.owner_chain, previous_owner
+        );
     }
 
     /// Receives a ticket from another chain.
+    /// Only the hub may deliver tickets whose policy restricts transfers: the initial mint
+    /// delivery for soulbound tickets, and mint or marketplace deliveries for resale-only ones.
     async fn receive_ticket(
         &mut self,
         ticket: Ticket,
-        target_chain: String,
-        _seller_chain: String,
+        target_chain: ChainId,
+        _seller_chain: ChainId,
         _sale_price: Option<u128>,
     ) {
+        let hub_chain = self.hub_chain();
+        let from_hub = self
+            .runtime
+            .message_origin_chain_id()
+            .is_some_and(|origin| origin == hub_chain);
+        let allowed = match ticket.transfer_policy {
+            TransferPolicy::Free => true,
+            TransferPolicy::ResaleOnly => from_hub,
+            TransferPolicy::Soulbound => from_hub && target_chain == ticket.minter_chain,
+        };
+        assert!(
+            allowed,
+            "ticket transfer policy {:?} forbids this transfer",
+            ticket.transfer_policy
+        );
+
         // Add to buyer's ownership
         let mut buyer_owned = self
             .state
@@ -869,46 +3148,58 @@ impl TicketingContract {
         buyer_owned.insert(ticket.ticket_id.clone());
         self.state.owned_ticket_ids.insert(&target_chain, buyer_owned).unwrap();
 
-        // Store ticket
+        // Store ticket, dropping any approval left from a previous stay on this chain
+        if from_hub {
+            self.note_verified_blob(ticket.metadata_hash);
+        }
         let ticket_id = ticket.ticket_id.clone();
+        self.state.ticket_approvals.remove(&ticket_id).unwrap();
         self.state.tickets.insert(&ticket_id, ticket).unwrap();
     }
 
     /// Sends a claim request to a remote chain.
     fn remote_claim(
         &mut self,
-        source_chain: String,
+        source_chain: ChainId,
         ticket_id: TicketId,
-        requester_chain: String,
-        new_owner: String,
+        requester_chain: ChainId,
+        claimant: AccountOwner,
+        new_owner: AccountOwner,
         sale_price: Option<u128>,
     ) {
-        if let Ok(source_chain_id) = source_chain.parse::<ChainId>() {
-            self.runtime.send_message(
-                source_chain_id,
-                Message::Claim {
-                    source_chain: source_chain.clone(),
-                    ticket_id,
-                    requester_chain,
-                    new_owner,
-                    sale_price,
-                },
-            );
-        }
+        self.runtime.send_message(
+            source_chain,
+            Message::Claim {
+                source_chain,
+                ticket_id,
+                requester_chain,
+                claimant,
+                new_owner,
+                sale_price,
+            },
+        );
     }
 
     /// Creates a marketplace listing locally (on hub).
-    async fn create_listing_local(&mut self, seller_chain: String, seller: String, ticket_id: TicketId, price: u128) {
+    async fn create_listing_local(
+        &mut self,
+        seller_chain: ChainId,
+        seller: AccountOwner,
+        ticket_id: TicketId,
+        price: u128,
+    ) {
         // On hub, verify ticket exists
         let ticket = self.get_ticket(&ticket_id).await;
-        assert_eq!(ticket.owner_chain, seller_chain, "Not the ticket owner chain");
-        // Case-insensitive comparison for wallet addresses
-        assert_eq!(ticket.owner.to_lowercase(), seller.to_lowercase(), "Not the ticket owner");
+        self.assert_owner_or_operator(&ticket, seller_chain, &seller).await;
+        self.assert_transferable(&ticket).await;
+        assert!(ticket.transfer_policy.allows_listing(), "soulbound tickets cannot be listed");
+        self.assert_resale_open(&ticket).await;
 
+        // Operators list on behalf of the owner, who receives the proceeds
         let listing = Listing {
             ticket_id: ticket_id.clone(),
-            seller_chain,
-            seller,
+            seller_chain: ticket.owner_chain,
+            seller: ticket.owner,
             price,
             status: ListingStatus::Active,
         };
@@ -939,16 +3230,13 @@ impl TicketingContract {
         self.state.ticket_history.insert(&ticket_id, history).unwrap();
         
         // Emit listing to stream for subscribers to sync
-        self.runtime.emit(
-            MARKETPLACE_STREAM.into(),
-            &StreamEvent::ListingCreated { listing },
-        );
+        self.emit_event(StreamEvent::ListingCreated { listing });
         
         eprintln!("[CREATE_LISTING] Listing created on hub and emitted to stream");
     }
 
     /// Cancels a marketplace listing locally (on hub).
-    async fn cancel_listing_local(&mut self, seller_chain: String, seller: String, ticket_id: TicketId) {
+    async fn cancel_listing_local(&mut self, seller_chain: ChainId, seller: AccountOwner, ticket_id: TicketId) {
         let listing = self
             .state
             .listings
@@ -970,25 +3258,30 @@ impl TicketingContract {
             return;
         }
         
-        assert_eq!(listing.seller_chain, seller_chain, "Not the seller chain");
-        // Case-insensitive comparison for wallet addresses
-        assert_eq!(listing.seller.to_lowercase(), seller.to_lowercase(), "Not the seller");
+        if listing.seller == seller {
+            assert_eq!(listing.seller_chain, seller_chain, "Not the seller chain");
+        } else {
+            let ticket = self.get_ticket(&ticket_id).await;
+            // Operators act from the owner chain, like the owner
+            assert_eq!(ticket.owner_chain, seller_chain, "Not the ticket owner chain");
+            assert!(
+                ticket.owner == listing.seller && self.is_approved_operator(&ticket, &seller).await,
+                "Not the seller or an approved operator"
+            );
+        }
 
         let mut updated = listing;
         updated.status = ListingStatus::Cancelled;
         self.state.listings.insert(&ticket_id, updated.clone()).unwrap();
         
         // Emit updated listing to stream
-        self.runtime.emit(
-            MARKETPLACE_STREAM.into(),
-            &StreamEvent::ListingUpdated { listing: updated },
-        );
+        self.emit_event(StreamEvent::ListingUpdated { listing: updated });
         
         eprintln!("[CANCEL_LISTING] Listing cancelled on hub and emitted to stream");
     }
 
     /// Buys a marketplace listing locally (on hub).
-    async fn buy_listing_local(&mut self, buyer_chain: String, buyer: String, ticket_id: TicketId, price: u128) {
+    async fn buy_listing_local(&mut self, buyer_chain: ChainId, buyer: AccountOwner, ticket_id: TicketId, price: u128) {
         let listing = self
             .state
             .listings
@@ -998,11 +3291,12 @@ impl TicketingContract {
             .expect("listing not found");
         assert_eq!(listing.status, ListingStatus::Active, "Listing not active");
         assert_eq!(listing.price, price, "Price mismatch");
-        // Prevent self-purchase (case-insensitive)
-        assert!(listing.seller.to_lowercase() != buyer.to_lowercase(), "Cannot buy your own listing");
+        // Prevent self-purchase
+        assert!(listing.seller != buyer, "Cannot buy your own listing");
 
         let ticket = self.get_ticket(&ticket_id).await;
-        let seller_chain = listing.seller_chain.clone();
+        self.assert_resale_open(&ticket).await;
+        let seller_chain = listing.seller_chain;
 
         // Mark listing as sold
         let mut updated = listing;
@@ -1010,13 +3304,377 @@ impl TicketingContract {
         self.state.listings.insert(&ticket_id, updated.clone()).unwrap();
         
         // Emit updated listing to stream
-        self.runtime.emit(
-            MARKETPLACE_STREAM.into(),
-            &StreamEvent::ListingUpdated { listing: updated },
-        );
+        self.emit_event(StreamEvent::ListingUpdated { listing: updated });
 
         // Transfer the ticket to the buyer
-        self.transfer(ticket, seller_chain, buyer_chain, buyer, Some(price)).await;
+        self.transfer(ticket, seller_chain, buyer_chain, buyer, Some(price), true).await;
         eprintln!("[BUY_LISTING] Listing purchased on hub and emitted to stream");
     }
 }
+
+/// Royalty owed to the organizer when a ticket sells at `sale_price`.
+fn sale_royalty(ticket: &Ticket, sale_price: Option<u128>) -> u128 {
+    sale_price
+        .map(|price| (price * ticket.royalty_bps as u128) / MAX_BPS as u128)
+        .unwrap_or(0)
+}
+
+/// Walks a collection's change log from the cursor (on hub) and returns the keys of one
+/// sync page with the cursor that follows it.
+async fn page_changes<K>(
+    log: &LogView<(u64, K)>,
+    changes: &MapView<K, u64>,
+    cursor: &SyncCursor,
+) -> (Vec<K>, Option<SyncCursor>)
+where
+    K: Serialize + DeserializeOwned + Clone + Send + Sync,
+{
+    let log_len = log.count() as u64;
+    let start = match cursor.position {
+        Some(position) => position,
+        None => first_change_after(log, cursor.since).await,
+    };
+    let mut pager = SyncPager::new(cursor, start);
+    while let Some(range) = pager.next_range(log_len) {
+        for (sequence, key) in log.read(range).await.unwrap() {
+            let latest = changes.get(&key).await.unwrap();
+            if !pager.offer(sequence, key, latest) {
+                break;
+            }
+        }
+    }
+    pager.finish(log_len)
+}
+
+/// Position of the first change-log entry after hub sequence `since` (binary search, as
+/// entries are appended in sequence order); 0 for a full sync.
+async fn first_change_after<K>(log: &LogView<(u64, K)>, since: Option<u64>) -> u64
+where
+    K: Serialize + DeserializeOwned + Clone + Send + Sync,
+{
+    let Some(since) = since else {
+        return 0;
+    };
+    let (mut low, mut high) = (0, log.count());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let (sequence, _) = log.get(middle).await.unwrap().expect("index within the log");
+        if sequence <= since {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    low as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::{
+        linera_base_types::{ApplicationId, CryptoHash, Timestamp},
+        util::BlockingWait,
+    };
+    use ticketing::BalanceEntry;
+
+    use super::*;
+
+    /// Runtime of the hub chain `hub`, in demo mode so placeholder blobs mint.
+    fn hub_runtime(hub: ChainId) -> ContractRuntime<TicketingContract> {
+        ContractRuntime::new()
+            .with_application_parameters(ApplicationParameters {
+                marketplace_chain: Some(hub),
+                demo_mode: true,
+                migration_admin: None,
+            })
+            .with_application_id(
+                ApplicationId::new(CryptoHash::test_hash("ticketing")).with_abi::<TicketingAbi>(),
+            )
+            .with_chain_id(hub)
+            .with_system_time(Timestamp::from(1_000_000_000))
+    }
+
+    #[test]
+    #[should_panic(expected = "owner is not on the presale allowlist")]
+    fn presale_chain_entry_does_not_admit_fans_a_box_office_mints_for() {
+        let hub = ChainId(CryptoHash::test_hash("hub"));
+        let fan = AccountOwner::from(CryptoHash::test_hash("fan"));
+        let runtime = hub_runtime(hub)
+            .with_authenticated_signer(AccountOwner::from(CryptoHash::test_hash("box-office")));
+        let mut contract = TicketingContract::load(runtime).blocking_wait();
+        let event_id = EventId { value: "gig".to_string() };
+        let event = Event {
+            id: event_id.clone(),
+            organizer_chain: hub,
+            name: "Gig".to_string(),
+            description: String::new(),
+            venue: String::new(),
+            start_time: 5_000,
+            royalty_bps: 0,
+            max_tickets: 10,
+            minted_tickets: 0,
+            released_tickets: 0,
+            image_url: None,
+            end_time: None,
+            base_price: None,
+            sale_start: None,
+            sale_end: None,
+            resale_start: None,
+            resale_end: None,
+            max_per_holder: None,
+            max_purchases_per_holder: None,
+            transfer_lockout_secs: None,
+            transfer_policy: TransferPolicy::Free,
+            collectible: None,
+        };
+        contract.state.events.insert(&event_id, event).unwrap();
+        let presale = Presale {
+            starts_at: None,
+            ends_at: 2_000,
+            allowlist: Allowlist::Full(BTreeSet::from([HolderKey::Chain(hub)])),
+        };
+        contract.state.presales.insert(&event_id, presale).unwrap();
+
+        contract
+            .execute_operation(Operation::MintTicket {
+                event_id,
+                seat: "A1".to_string(),
+                blob_hash: DataBlobHash(CryptoHash::from([0u8; 32])),
+                owner: Some(fan),
+                image_url: None,
+                transfer_policy: None,
+                presale_proof: Vec::new(),
+            })
+            .blocking_wait();
+    }
+
+    #[test]
+    fn presale_admits_allowlisted_recipients_and_self_minting_chains() {
+        let hub = ChainId(CryptoHash::test_hash("hub"));
+        let fan = AccountOwner::from(CryptoHash::test_hash("fan"));
+        let runtime = hub_runtime(hub)
+            .with_authenticated_signer(AccountOwner::from(CryptoHash::test_hash("box-office")));
+        let mut contract = TicketingContract::load(runtime).blocking_wait();
+        let event_id = EventId { value: "gig".to_string() };
+        let event = Event {
+            id: event_id.clone(),
+            organizer_chain: hub,
+            name: "Gig".to_string(),
+            description: String::new(),
+            venue: String::new(),
+            start_time: 5_000,
+            royalty_bps: 0,
+            max_tickets: 10,
+            minted_tickets: 0,
+            released_tickets: 0,
+            image_url: None,
+            end_time: None,
+            base_price: None,
+            sale_start: None,
+            sale_end: None,
+            resale_start: None,
+            resale_end: None,
+            max_per_holder: None,
+            max_purchases_per_holder: None,
+            transfer_lockout_secs: None,
+            transfer_policy: TransferPolicy::Free,
+            collectible: None,
+        };
+        contract.state.events.insert(&event_id, event).unwrap();
+        let presale = Presale {
+            starts_at: None,
+            ends_at: 2_000,
+            allowlist: Allowlist::Full(BTreeSet::from([HolderKey::Owner(fan), HolderKey::Chain(hub)])),
+        };
+        contract.state.presales.insert(&event_id, presale).unwrap();
+
+        // A fan on the allowlist, minted for by the box office
+        contract
+            .execute_operation(Operation::MintTicket {
+                event_id: event_id.clone(),
+                seat: "A1".to_string(),
+                blob_hash: DataBlobHash(CryptoHash::from([0u8; 32])),
+                owner: Some(fan),
+                image_url: None,
+                transfer_policy: None,
+                presale_proof: Vec::new(),
+            })
+            .blocking_wait();
+        // The signer minting to themselves, admitted through their chain's entry
+        contract
+            .execute_operation(Operation::MintTicket {
+                event_id: event_id.clone(),
+                seat: "A2".to_string(),
+                blob_hash: DataBlobHash(CryptoHash::from([0u8; 32])),
+                owner: None,
+                image_url: None,
+                transfer_policy: None,
+                presale_proof: Vec::new(),
+            })
+            .blocking_wait();
+
+        let event = contract.state.events.get(&event_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(event.minted_tickets, 2);
+        let fan_holdings = contract.state.holdings.get(&(event_id, HolderKey::Owner(fan)));
+        assert_eq!(fan_holdings.blocking_wait().unwrap(), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Message claims to come from another chain")]
+    fn approvals_for_all_cannot_be_set_on_behalf_of_another_chain() {
+        let hub = ChainId(CryptoHash::test_hash("hub"));
+        let runtime = hub_runtime(hub)
+            .with_message_origin_chain_id(ChainId(CryptoHash::test_hash("mallory-chain")))
+            .with_message_is_bouncing(false);
+        let mut contract = TicketingContract::load(runtime).blocking_wait();
+
+        contract
+            .execute_message(Message::SetApprovalForAllOnHub {
+                requester_chain: ChainId(CryptoHash::test_hash("alice-chain")),
+                owner: AccountOwner::from(CryptoHash::test_hash("alice")),
+                operator: AccountOwner::from(CryptoHash::test_hash("mallory")),
+                approved: true,
+            })
+            .blocking_wait();
+    }
+
+    #[test]
+    fn approvals_for_all_only_cover_tickets_on_the_approving_chain() {
+        let hub = ChainId(CryptoHash::test_hash("hub"));
+        let alice_chain = ChainId(CryptoHash::test_hash("alice-chain"));
+        let alice = AccountOwner::from(CryptoHash::test_hash("alice"));
+        let operator = AccountOwner::from(CryptoHash::test_hash("operator"));
+        let mut contract = TicketingContract::load(hub_runtime(hub)).blocking_wait();
+        contract.set_approval_for_all(alice_chain, alice, operator, true).blocking_wait();
+
+        let ticket = Ticket {
+            ticket_id: TicketId { id: vec![1; 32] },
+            event_id: EventId { value: "gig".to_string() },
+            event_name: "Gig".to_string(),
+            seat: "A1".to_string(),
+            organizer_chain: hub,
+            owner_chain: alice_chain,
+            owner: alice,
+            minter_chain: hub,
+            royalty_bps: 0,
+            metadata_hash: DataBlobHash(CryptoHash::from([0u8; 32])),
+            last_sale_price: None,
+            image_url: None,
+            transfer_policy: TransferPolicy::Free,
+            minted_at: 0,
+            status: TicketStatus::Valid,
+            redemption: None,
+            mint_index: 0,
+        };
+        assert!(contract.is_approved_operator(&ticket, &operator).blocking_wait());
+
+        // The same owner account holding a ticket on another chain did not approve there
+        let elsewhere = Ticket {
+            owner_chain: ChainId(CryptoHash::test_hash("other-chain")),
+            ..ticket
+        };
+        assert!(!contract.is_approved_operator(&elsewhere, &operator).blocking_wait());
+    }
+
+    #[test]
+    fn bounced_sale_returns_the_ticket_royalty_and_listing() {
+        let hub = ChainId(CryptoHash::test_hash("hub"));
+        let organizer_chain = ChainId(CryptoHash::test_hash("organizer"));
+        let seller_chain = ChainId(CryptoHash::test_hash("seller-chain"));
+        let buyer_chain = ChainId(CryptoHash::test_hash("buyer-chain"));
+        let seller = AccountOwner::from(CryptoHash::test_hash("seller"));
+        let buyer = AccountOwner::from(CryptoHash::test_hash("buyer"));
+        let runtime = hub_runtime(hub)
+            .with_message_origin_chain_id(buyer_chain)
+            .with_message_is_bouncing(true);
+        let mut contract = TicketingContract::load(runtime).blocking_wait();
+
+        // The hub's copy after the sale: bought for 100 with a 10% royalty
+        let event_id = EventId { value: "gig".to_string() };
+        let ticket_id = TicketId { id: vec![1; 32] };
+        let sold = Ticket {
+            ticket_id: ticket_id.clone(),
+            event_id: event_id.clone(),
+            event_name: "Gig".to_string(),
+            seat: "A1".to_string(),
+            organizer_chain,
+            owner_chain: buyer_chain,
+            owner: buyer,
+            minter_chain: organizer_chain,
+            royalty_bps: 1_000,
+            metadata_hash: DataBlobHash(CryptoHash::from([0u8; 32])),
+            last_sale_price: Some(100),
+            image_url: None,
+            transfer_policy: TransferPolicy::Free,
+            minted_at: 0,
+            status: TicketStatus::Valid,
+            redemption: None,
+            mint_index: 0,
+        };
+        contract.state.tickets.insert(&ticket_id, sold.clone()).unwrap();
+        contract.state.holdings.insert(&(event_id.clone(), HolderKey::Owner(buyer)), 1).unwrap();
+        contract.state.royalty_balances.insert(&organizer_chain, BalanceEntry { pending: 10 }).unwrap();
+        contract.state.total_royalties.set(10);
+        let listing = Listing {
+            ticket_id: ticket_id.clone(),
+            seller_chain,
+            seller,
+            price: 100,
+            status: ListingStatus::Sold,
+        };
+        contract.state.listings.insert(&ticket_id, listing.clone()).unwrap();
+
+        contract
+            .execute_message(Message::Transfer {
+                ticket: sold,
+                target_chain: buyer_chain,
+                seller_chain,
+                sale_price: Some(100),
+                previous_owner: seller,
+                previous_sale_price: None,
+                sold_listing: Some(listing),
+            })
+            .blocking_wait();
+
+        let restored = contract.state.tickets.get(&ticket_id).blocking_wait().unwrap().unwrap();
+        assert_eq!((restored.owner, restored.owner_chain), (seller, seller_chain));
+        assert_eq!(restored.last_sale_price, None);
+        let balance = contract.state.royalty_balances.get(&organizer_chain).blocking_wait().unwrap();
+        assert_eq!(balance, Some(BalanceEntry { pending: 0 }));
+        assert_eq!(*contract.state.total_royalties.get(), 0);
+        let listing = contract.state.listings.get(&ticket_id).blocking_wait().unwrap().unwrap();
+        assert_eq!(listing.status, ListingStatus::Active);
+        let buyer_holdings = contract.state.holdings.get(&(event_id.clone(), HolderKey::Owner(buyer)));
+        assert_eq!(buyer_holdings.blocking_wait().unwrap(), None);
+        let seller_holdings = contract.state.holdings.get(&(event_id, HolderKey::Owner(seller)));
+        assert_eq!(seller_holdings.blocking_wait().unwrap(), Some(1));
+    }
+
+    #[test]
+    fn sync_pages_send_each_changed_record_once() {
+        let hub = ChainId(CryptoHash::test_hash("hub"));
+        let mut contract = TicketingContract::load(hub_runtime(hub)).blocking_wait();
+        let first = EventId { value: "first".to_string() };
+        let second = EventId { value: "second".to_string() };
+        let third = EventId { value: "third".to_string() };
+        contract.record_event_change(&first, 1);
+        contract.record_event_change(&second, 2);
+        contract.record_event_change(&third, 3);
+        contract.record_event_change(&first, 4);
+
+        // A full sync sends the first event at its latest change only
+        let cursor = SyncCursor::start(SyncCollection::Events, None);
+        let (keys, next) =
+            page_changes(&contract.state.event_log, &contract.state.event_changes, &cursor)
+                .blocking_wait();
+        assert_eq!(keys, vec![second.clone(), third.clone(), first.clone()]);
+        assert_eq!(next, None);
+
+        // A delta sync skips what the subscriber already has
+        let cursor = SyncCursor::start(SyncCollection::Events, Some(2));
+        let (keys, next) =
+            page_changes(&contract.state.event_log, &contract.state.event_changes, &cursor)
+                .blocking_wait();
+        assert_eq!(keys, vec![third, first]);
+        assert_eq!(next, None);
+    }
+}
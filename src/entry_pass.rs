@@ -0,0 +1,413 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/*! Signed entry passes that door staff can verify without a live node.
+ *
+ *  Flow:
+ *  - The ticket owner's wallet signs a short-lived `EntryPass` (shown as a QR code)
+ *  - The hub publishes a `RedeemedSnapshot` per event (service query `redeemedSnapshot`):
+ *    the event's tickets with their current owners, and those already redeemed
+ *  - Scanner devices load the snapshot before doors open and verify passes offline
+ *    with `EntryPassVerifier`, which needs no network access
+ */
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use linera_sdk::linera_base_types::{
+    AccountOwner, AccountSignature, ApplicationId, BcsSignable, ChainId, CryptoHash, DataBlobHash,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{EventId, Ticket, TicketId};
+
+/// Longest validity a pass may claim (5 minutes), so a leaked QR code expires quickly.
+pub const MAX_ENTRY_PASS_TTL_MS: u64 = 5 * 60 * 1000;
+
+/// Tolerated clock drift between the wallet and the scanner device.
+pub const ENTRY_PASS_CLOCK_SKEW_MS: u64 = 30 * 1000;
+
+/// The statement a ticket owner signs to enter the venue.
+/// Carries the mint inputs so the verifier can recompute the ticket ID.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryPass {
+    pub ticket_id: TicketId,
+    pub event_id: EventId,
+    /// Ticket owner (must match the signer)
+    pub owner: AccountOwner,
+    pub seat: String,
+    pub minter_chain: ChainId,
+    pub metadata_hash: DataBlobHash,
+    pub mint_index: u32,
+    /// Random value chosen by the wallet so each pass is unique
+    pub nonce: u64,
+    /// Unix ms when the pass was signed
+    pub issued_at: u64,
+    /// Unix ms after which the pass is rejected
+    pub expires_at: u64,
+}
+
+impl<'de> BcsSignable<'de> for EntryPass {}
+
+impl EntryPass {
+    /// Builds a pass for a ticket, valid from `issued_at` for `ttl_ms`.
+    pub fn for_ticket(ticket: &Ticket, nonce: u64, issued_at: u64, ttl_ms: u64) -> Self {
+        Self {
+            ticket_id: ticket.ticket_id.clone(),
+            event_id: ticket.event_id.clone(),
+            owner: ticket.owner,
+            seat: ticket.seat.clone(),
+            minter_chain: ticket.minter_chain,
+            metadata_hash: ticket.metadata_hash,
+            mint_index: ticket.mint_index,
+            nonce,
+            issued_at,
+            expires_at: issued_at.saturating_add(ttl_ms),
+        }
+    }
+
+    /// Hash the owner's wallet signs.
+    pub fn signing_hash(&self) -> CryptoHash {
+        CryptoHash::new(self)
+    }
+}
+
+/// An entry pass together with the owner's signature.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SignedEntryPass {
+    pub pass: EntryPass,
+    pub signature: AccountSignature,
+}
+
+/// Tickets of an event as published by the hub: who holds each one, and which were redeemed.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemedSnapshot {
+    pub event_id: EventId,
+    /// Unix ms when the hub produced the snapshot
+    pub taken_at: u64,
+    /// Current owner of every ticket of the event (burned tickets are absent)
+    pub holders: BTreeMap<TicketId, AccountOwner>,
+    pub redeemed: BTreeSet<TicketId>,
+}
+
+/// Reasons an entry pass is refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryPassError {
+    /// The pass is for a different event than the one being scanned
+    WrongEvent,
+    /// The pass is expired, not yet valid, or claims too long a validity
+    Expired,
+    /// The signature does not verify against the pass contents
+    InvalidSignature,
+    /// The signer is not the ticket owner named in the pass
+    OwnerMismatch,
+    /// The ticket ID does not match the mint inputs in the pass
+    TicketIdMismatch,
+    /// The snapshot has no such ticket (never minted, or burned)
+    UnknownTicket,
+    /// The pass owner no longer holds the ticket, per the snapshot
+    NotHolder,
+    /// The ticket was already checked in (per the snapshot or at this device)
+    AlreadyRedeemed,
+    /// The ticket ID could not be recomputed
+    Serialization(String),
+}
+
+impl fmt::Display for EntryPassError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongEvent => write!(f, "entry pass is for another event"),
+            Self::Expired => write!(f, "entry pass is expired or not yet valid"),
+            Self::InvalidSignature => write!(f, "entry pass signature is invalid"),
+            Self::OwnerMismatch => write!(f, "entry pass is not signed by the ticket owner"),
+            Self::TicketIdMismatch => write!(f, "ticket ID does not match the ticket data"),
+            Self::UnknownTicket => write!(f, "ticket is not part of this event"),
+            Self::NotHolder => write!(f, "entry pass owner does not hold the ticket"),
+            Self::AlreadyRedeemed => write!(f, "ticket was already redeemed"),
+            Self::Serialization(error) => write!(f, "failed to derive ticket ID: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for EntryPassError {}
+
+/// Offline verifier run by scanner devices at the door.
+#[derive(Debug, Clone)]
+pub struct EntryPassVerifier {
+    /// Hub chain that minted the tickets
+    pub hub_chain: ChainId,
+    pub application_id: ApplicationId,
+    /// Last snapshot loaded from the hub
+    pub snapshot: RedeemedSnapshot,
+    /// Tickets admitted by this device since the snapshot was loaded
+    pub admitted: BTreeSet<TicketId>,
+}
+
+impl EntryPassVerifier {
+    pub fn new(hub_chain: ChainId, application_id: ApplicationId, snapshot: RedeemedSnapshot) -> Self {
+        Self {
+            hub_chain,
+            application_id,
+            snapshot,
+            admitted: BTreeSet::new(),
+        }
+    }
+
+    /// Checks a pass at `now` (Unix ms) without admitting it.
+    pub fn verify(&self, signed: &SignedEntryPass, now: u64) -> Result<(), EntryPassError> {
+        let pass = &signed.pass;
+        if pass.event_id != self.snapshot.event_id {
+            return Err(EntryPassError::WrongEvent);
+        }
+        let within_ttl = pass.expires_at >= pass.issued_at
+            && pass.expires_at - pass.issued_at <= MAX_ENTRY_PASS_TTL_MS;
+        if !within_ttl
+            || pass.issued_at > now.saturating_add(ENTRY_PASS_CLOCK_SKEW_MS)
+            || now > pass.expires_at
+        {
+            return Err(EntryPassError::Expired);
+        }
+
+        signed
+            .signature
+            .verify(pass)
+            .map_err(|_| EntryPassError::InvalidSignature)?;
+        if signed.signature.owner() != pass.owner {
+            return Err(EntryPassError::OwnerMismatch);
+        }
+
+        let ticket_id = Ticket::create_ticket_id(
+            &self.hub_chain,
+            &self.application_id,
+            &pass.event_id,
+            &pass.seat,
+            &pass.minter_chain,
+            &pass.metadata_hash,
+            pass.mint_index,
+        )
+        .map_err(|error| EntryPassError::Serialization(error.to_string()))?;
+        if ticket_id != pass.ticket_id {
+            return Err(EntryPassError::TicketIdMismatch);
+        }
+        // Mint inputs are public, so only the hub's ownership record proves who may enter
+        match self.snapshot.holders.get(&ticket_id) {
+            None => return Err(EntryPassError::UnknownTicket),
+            Some(holder) if *holder != pass.owner => return Err(EntryPassError::NotHolder),
+            Some(_) => {}
+        }
+
+        if self.snapshot.redeemed.contains(&ticket_id) || self.admitted.contains(&ticket_id) {
+            return Err(EntryPassError::AlreadyRedeemed);
+        }
+        Ok(())
+    }
+
+    /// Verifies a pass and, if valid, records the ticket so this device refuses it again.
+    pub fn admit(&mut self, signed: &SignedEntryPass, now: u64) -> Result<TicketId, EntryPassError> {
+        self.verify(signed, now)?;
+        let ticket_id = signed.pass.ticket_id.clone();
+        self.admitted.insert(ticket_id.clone());
+        Ok(ticket_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::linera_base_types::{AccountSecretKey, Ed25519SecretKey};
+
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000_000;
+
+    #[test]
+    fn holder_pass_is_admitted_once() {
+        let hub_chain = ChainId(CryptoHash::test_hash("hub"));
+        let application_id = ApplicationId::new(CryptoHash::test_hash("ticketing"));
+        let event_id = EventId { value: "concert".to_string() };
+        let minter_chain = ChainId(CryptoHash::test_hash("minter"));
+        let metadata_hash = DataBlobHash(CryptoHash::test_hash("metadata"));
+        let ticket_id = Ticket::create_ticket_id(
+            &hub_chain,
+            &application_id,
+            &event_id,
+            "A1",
+            &minter_chain,
+            &metadata_hash,
+            0,
+        )
+        .unwrap();
+        let owner_key = AccountSecretKey::Ed25519(Ed25519SecretKey::generate());
+        let owner = AccountOwner::from(owner_key.public());
+        let snapshot = RedeemedSnapshot {
+            event_id: event_id.clone(),
+            taken_at: NOW,
+            holders: BTreeMap::from([(ticket_id.clone(), owner)]),
+            redeemed: BTreeSet::new(),
+        };
+        let mut verifier = EntryPassVerifier::new(hub_chain, application_id, snapshot);
+
+        let pass = EntryPass {
+            ticket_id: ticket_id.clone(),
+            event_id,
+            owner,
+            seat: "A1".to_string(),
+            minter_chain,
+            metadata_hash,
+            mint_index: 0,
+            nonce: 7,
+            issued_at: NOW,
+            expires_at: NOW + 60_000,
+        };
+        let signature = owner_key.sign(&pass);
+        let signed = SignedEntryPass { pass, signature };
+        assert_eq!(verifier.admit(&signed, NOW), Ok(ticket_id));
+        assert_eq!(verifier.admit(&signed, NOW), Err(EntryPassError::AlreadyRedeemed));
+    }
+
+    #[test]
+    fn stranger_naming_themselves_owner_is_refused() {
+        let hub_chain = ChainId(CryptoHash::test_hash("hub"));
+        let application_id = ApplicationId::new(CryptoHash::test_hash("ticketing"));
+        let event_id = EventId { value: "concert".to_string() };
+        let minter_chain = ChainId(CryptoHash::test_hash("minter"));
+        let metadata_hash = DataBlobHash(CryptoHash::test_hash("metadata"));
+        let ticket_id = Ticket::create_ticket_id(
+            &hub_chain,
+            &application_id,
+            &event_id,
+            "A1",
+            &minter_chain,
+            &metadata_hash,
+            0,
+        )
+        .unwrap();
+        let holder = AccountOwner::from(CryptoHash::test_hash("holder"));
+        let snapshot = RedeemedSnapshot {
+            event_id: event_id.clone(),
+            taken_at: NOW,
+            holders: BTreeMap::from([(ticket_id.clone(), holder)]),
+            redeemed: BTreeSet::new(),
+        };
+        let verifier = EntryPassVerifier::new(hub_chain, application_id, snapshot);
+
+        // A validly signed pass, but by someone who does not hold the ticket
+        let stranger_key = AccountSecretKey::Ed25519(Ed25519SecretKey::generate());
+        let pass = EntryPass {
+            ticket_id,
+            event_id,
+            owner: AccountOwner::from(stranger_key.public()),
+            seat: "A1".to_string(),
+            minter_chain,
+            metadata_hash,
+            mint_index: 0,
+            nonce: 7,
+            issued_at: NOW,
+            expires_at: NOW + 60_000,
+        };
+        let signature = stranger_key.sign(&pass);
+        let signed = SignedEntryPass { pass, signature };
+        assert_eq!(verifier.verify(&signed, NOW), Err(EntryPassError::NotHolder));
+    }
+
+    #[test]
+    fn ticket_missing_from_snapshot_is_refused() {
+        let hub_chain = ChainId(CryptoHash::test_hash("hub"));
+        let application_id = ApplicationId::new(CryptoHash::test_hash("ticketing"));
+        let event_id = EventId { value: "concert".to_string() };
+        let minter_chain = ChainId(CryptoHash::test_hash("minter"));
+        let metadata_hash = DataBlobHash(CryptoHash::test_hash("metadata"));
+        let owner_key = AccountSecretKey::Ed25519(Ed25519SecretKey::generate());
+        let owner = AccountOwner::from(owner_key.public());
+        let snapshot = RedeemedSnapshot {
+            event_id: event_id.clone(),
+            taken_at: NOW,
+            holders: BTreeMap::new(),
+            redeemed: BTreeSet::new(),
+        };
+        let verifier = EntryPassVerifier::new(hub_chain, application_id, snapshot);
+
+        // Derives to a real ticket ID, but one the hub never minted
+        let ticket_id = Ticket::create_ticket_id(
+            &hub_chain,
+            &application_id,
+            &event_id,
+            "B7",
+            &minter_chain,
+            &metadata_hash,
+            0,
+        )
+        .unwrap();
+        let pass = EntryPass {
+            ticket_id,
+            event_id,
+            owner,
+            seat: "B7".to_string(),
+            minter_chain,
+            metadata_hash,
+            mint_index: 0,
+            nonce: 7,
+            issued_at: NOW,
+            expires_at: NOW + 60_000,
+        };
+        let signature = owner_key.sign(&pass);
+        let signed = SignedEntryPass { pass, signature };
+        assert_eq!(verifier.verify(&signed, NOW), Err(EntryPassError::UnknownTicket));
+    }
+
+    #[test]
+    fn expired_and_redeemed_passes_are_refused() {
+        let hub_chain = ChainId(CryptoHash::test_hash("hub"));
+        let application_id = ApplicationId::new(CryptoHash::test_hash("ticketing"));
+        let event_id = EventId { value: "concert".to_string() };
+        let minter_chain = ChainId(CryptoHash::test_hash("minter"));
+        let metadata_hash = DataBlobHash(CryptoHash::test_hash("metadata"));
+        let ticket_id = Ticket::create_ticket_id(
+            &hub_chain,
+            &application_id,
+            &event_id,
+            "A1",
+            &minter_chain,
+            &metadata_hash,
+            0,
+        )
+        .unwrap();
+        let owner_key = AccountSecretKey::Ed25519(Ed25519SecretKey::generate());
+        let owner = AccountOwner::from(owner_key.public());
+        let pass = EntryPass {
+            ticket_id: ticket_id.clone(),
+            event_id: event_id.clone(),
+            owner,
+            seat: "A1".to_string(),
+            minter_chain,
+            metadata_hash,
+            mint_index: 0,
+            nonce: 7,
+            issued_at: NOW,
+            expires_at: NOW + 60_000,
+        };
+        let signature = owner_key.sign(&pass);
+        let signed = SignedEntryPass { pass, signature };
+
+        let snapshot = RedeemedSnapshot {
+            event_id: event_id.clone(),
+            taken_at: NOW,
+            holders: BTreeMap::from([(ticket_id.clone(), owner)]),
+            redeemed: BTreeSet::new(),
+        };
+        let verifier = EntryPassVerifier::new(hub_chain, application_id, snapshot);
+        assert_eq!(verifier.verify(&signed, NOW + 60_001), Err(EntryPassError::Expired));
+
+        let snapshot = RedeemedSnapshot {
+            event_id,
+            taken_at: NOW,
+            holders: BTreeMap::from([(ticket_id.clone(), owner)]),
+            redeemed: BTreeSet::from([ticket_id]),
+        };
+        let verifier = EntryPassVerifier::new(hub_chain, application_id, snapshot);
+        assert_eq!(verifier.verify(&signed, NOW), Err(EntryPassError::AlreadyRedeemed));
+    }
+}
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 /*! ABI of the Event Ticketing example with embedded royalty accounting.
- *  Uses chain_id as identity - no owner parameters needed in mutations.
+ *  Uses chain_id plus the block's authenticated signer as identity; caller-supplied
+ *  owner addresses are only trusted in demo mode (see `ApplicationParameters::demo_mode`).
  *  
  *  Hub-and-Spoke Architecture:
  *  - The "marketplace chain" (where app was created) is the hub
@@ -14,24 +15,65 @@
 use async_graphql::{InputObject, Request, Response, SimpleObject};
 use linera_sdk::{
     linera_base_types::{
-        ApplicationId, ChainId, ContractAbi, DataBlobHash, ServiceAbi,
+        AccountOwner, ApplicationId, ChainId, ContractAbi, DataBlobHash, ServiceAbi,
     },
     ToBcsBytes,
 };
+use std::collections::BTreeSet;
+
 use serde::{Deserialize, Serialize};
 
+pub mod entry_pass;
+pub mod migration;
+pub mod presale;
+
+pub use entry_pass::{
+    EntryPass, EntryPassError, EntryPassVerifier, RedeemedSnapshot, SignedEntryPass,
+};
+pub use presale::{Allowlist, MerkleHash, Presale};
+
 /// Stream name for marketplace events (events, tickets, listings)
 pub const MARKETPLACE_STREAM: &[u8] = b"marketplace";
 
 /// Maximum number of basis points used for royalty splits.
 pub const MAX_BPS: u16 = 10_000;
 
+/// Maximum number of seats a single `MintTicketBatch` may mint.
+pub const MAX_MINT_BATCH: usize = 500;
+
+/// Maximum number of records the hub sends in one `Message::SyncPage`.
+pub const SYNC_PAGE_SIZE: usize = 100;
+
+/// Maximum number of change-log entries the hub walks for one sync page, so records
+/// changed many times cannot make a page's work unbounded.
+pub const SYNC_SCAN_LIMIT: usize = 4 * SYNC_PAGE_SIZE;
+
+/// Returns the canonical label used to enforce seat uniqueness within an event.
+/// Seats compare case-insensitively with surrounding whitespace ignored.
+/// Empty labels and "GA" denote general admission and are never reserved (None).
+pub fn normalize_seat(seat: &str) -> Option<String> {
+    let seat = seat.trim().to_uppercase();
+    if seat.is_empty() || seat == "GA" {
+        None
+    } else {
+        Some(seat)
+    }
+}
+
 /// Application parameters - shared across all chains
 /// Contains the marketplace (hub) chain ID where shared data lives
 #[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct ApplicationParameters {
-    /// The chain ID where shared marketplace data lives (usually the creation chain)
-    pub marketplace_chain: String,
+    /// The chain ID where shared marketplace data lives; None = the creation chain
+    pub marketplace_chain: Option<ChainId>,
+    /// Demo mode: many wallets share the hub chain without signing blocks, so the owner,
+    /// seller and buyer addresses given in operations are trusted as is.
+    /// When off, identity comes from the block's authenticated signer.
+    #[serde(default)]
+    pub demo_mode: bool,
+    /// Account allowed to import state from an older deployment (see `migration`)
+    #[serde(default)]
+    pub migration_admin: Option<AccountOwner>,
 }
 
 /// Event identifier.
@@ -44,6 +86,64 @@ pub struct EventId {
     pub value: String,
 }
 
+/// Number of leading organizer chain ID characters used as the event namespace.
+const EVENT_NAMESPACE_LEN: usize = 16;
+
+impl EventId {
+    /// Namespace prefix reserved for events organized by `organizer_chain`.
+    pub fn namespace(organizer_chain: &ChainId) -> String {
+        let mut namespace = organizer_chain.to_string();
+        namespace.truncate(EVENT_NAMESPACE_LEN);
+        namespace
+    }
+
+    /// Builds an organizer-scoped ID `<namespace>/<slug>`.
+    /// Slugs that are already scoped to the organizer are kept as is.
+    pub fn scoped(organizer_chain: &ChainId, slug: &str) -> EventId {
+        let slug = slug.trim();
+        let candidate = EventId { value: slug.to_string() };
+        if candidate.is_scoped_to(organizer_chain) {
+            return candidate;
+        }
+        EventId {
+            value: format!("{}/{}", Self::namespace(organizer_chain), slug),
+        }
+    }
+
+    /// Resolves the ID a new event of `organizer_chain` gets for the requested slug.
+    /// An empty slug takes the first nonce from `nonce` up whose ID `is_taken` rejects;
+    /// the nonce after it is returned for the organizer to store.
+    pub async fn resolve<F, Fut>(
+        organizer_chain: &ChainId,
+        slug: &str,
+        mut nonce: u64,
+        mut is_taken: F,
+    ) -> (EventId, Option<u64>)
+    where
+        F: FnMut(EventId) -> Fut,
+        Fut: std::future::Future<Output = bool>,
+    {
+        if !slug.trim().is_empty() {
+            return (Self::scoped(organizer_chain, slug), None);
+        }
+        loop {
+            let candidate = Self::scoped(organizer_chain, &nonce.to_string());
+            nonce += 1;
+            if !is_taken(candidate.clone()).await {
+                return (candidate, Some(nonce));
+            }
+        }
+    }
+
+    /// Returns true if this ID lives in the namespace of `organizer_chain`.
+    pub fn is_scoped_to(&self, organizer_chain: &ChainId) -> bool {
+        self.value
+            .strip_prefix(Self::namespace(organizer_chain).as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+            .is_some_and(|slug| !slug.is_empty())
+    }
+}
+
 /// Ticket identifier stored as raw bytes and shared via base64.
 #[derive(
     Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Ord, PartialOrd, SimpleObject, InputObject,
@@ -53,6 +153,43 @@ pub struct TicketId {
     pub id: Vec<u8>,
 }
 
+/// Row/number seat pattern, e.g. row "A" from 1 to 20 expands to "A1".."A20".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, InputObject)]
+pub struct SeatRange {
+    pub row: String,
+    /// First seat number (inclusive)
+    pub from: u32,
+    /// Last seat number (inclusive)
+    pub to: u32,
+}
+
+impl SeatRange {
+    /// Seat labels covered by this range, in order.
+    pub fn seats(&self) -> impl Iterator<Item = String> + '_ {
+        (self.from..=self.to).map(move |number| format!("{}{}", self.row, number))
+    }
+}
+
+/// Number of seats `expand_seats` would produce, counted without expanding the ranges.
+/// None if a range ends before it starts.
+pub fn batch_size(seats: &[String], ranges: &[SeatRange]) -> Option<u64> {
+    ranges.iter().try_fold(seats.len() as u64, |total, range| {
+        (range.to >= range.from).then(|| total + u64::from(range.to - range.from) + 1)
+    })
+}
+
+/// Expands an explicit seat list plus range patterns into the full list of seat labels.
+/// Panics on a reversed range or a batch over `MAX_MINT_BATCH`, before building any label.
+pub fn expand_seats(seats: &[String], ranges: &[SeatRange]) -> Vec<String> {
+    let count = batch_size(seats, ranges).expect("seat range ends before it starts");
+    assert!(count <= MAX_MINT_BATCH as u64, "batch exceeds {} seats", MAX_MINT_BATCH);
+    let mut expanded = seats.to_vec();
+    for range in ranges {
+        expanded.extend(range.seats());
+    }
+    expanded
+}
+
 pub struct TicketingAbi;
 
 impl ContractAbi for TicketingAbi {
@@ -66,10 +203,13 @@ impl ServiceAbi for TicketingAbi {
 }
 
 /// Operations supported by the ticketing contract.
-/// Operations include owner/seller address for demo mode (shared hub chain).
+/// Owner/seller/buyer fields are only honored in demo mode (shared hub chain); otherwise
+/// they may be omitted and must match the block's authenticated signer when given.
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Operation {
     /// Registers a new event with royalty terms.
+    /// event_id is a slug scoped to the caller chain (see `EventId::scoped`);
+    /// an empty slug lets the organizer chain assign the next nonce.
     CreateEvent {
         event_id: EventId,
         name: String,
@@ -82,119 +222,385 @@ pub enum Operation {
         image_url: Option<String>,
         end_time: Option<u64>,
         base_price: Option<u128>,
+        /// Primary sales window (Unix seconds, like start_time); None leaves that side open
+        sale_start: Option<u64>,
+        sale_end: Option<u64>,
+        /// Secondary (resale) listing window; None leaves that side open
+        resale_start: Option<u64>,
+        resale_end: Option<u64>,
+        /// Max tickets one owner address or chain may hold for this event
+        max_per_holder: Option<u32>,
+        /// Max tickets one owner address or chain may mint or buy for this event
+        max_purchases_per_holder: Option<u32>,
+        /// Seconds before start_time during which transfers and resales are frozen
+        transfer_lockout_secs: Option<u64>,
+        /// Default transferability of the event's tickets
+        transfer_policy: TransferPolicy,
+    },
+    /// Updates the pre-show transfer lockout of an event (organizer or event admin only).
+    SetTransferLockout {
+        event_id: EventId,
+        lockout_secs: Option<u64>,
+    },
+    /// Updates the per-holder caps of an event (organizer or event admin only).
+    SetHolderLimits {
+        event_id: EventId,
+        max_per_holder: Option<u32>,
+        max_purchases_per_holder: Option<u32>,
+    },
+    /// Sets or clears the presale of an event (organizer or event admin only).
+    /// While the presale is open only allowlisted owners or chains can receive minted tickets.
+    SetPresale {
+        event_id: EventId,
+        presale: Option<Presale>,
+    },
+    /// Adds and removes entries of a full presale allowlist (organizer or event admin only),
+    /// so large lists can be uploaded over several operations.
+    UpdatePresaleAllowlist {
+        event_id: EventId,
+        added: Vec<HolderKey>,
+        removed: Vec<HolderKey>,
     },
     /// Mints a ticket for a seat within an event.
-    /// owner: wallet address of the minter (for demo mode)
+    /// owner: wallet address of the minter (demo mode)
     MintTicket {
         event_id: EventId,
         seat: String,
         blob_hash: DataBlobHash,
-        owner: String,
+        owner: Option<AccountOwner>,
         // Wave 6: Enhanced metadata
         image_url: Option<String>,
+        /// Overrides the event's transfer policy for this ticket (e.g. an ID-checked tier)
+        transfer_policy: Option<TransferPolicy>,
+        /// Merkle proof of the owner (or of this chain, when the owner signs the mint) for
+        /// presales with a Merkle allowlist
+        presale_proof: Vec<MerkleHash>,
+    },
+    /// Mints many seats of one event at once, from an explicit list and/or row ranges.
+    /// All tickets share the same metadata blob and are minted in a single hub message.
+    MintTicketBatch {
+        event_id: EventId,
+        seats: Vec<String>,
+        ranges: Vec<SeatRange>,
+        blob_hash: DataBlobHash,
+        owner: Option<AccountOwner>,
+        image_url: Option<String>,
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: Vec<MerkleHash>,
     },
     /// Transfers a ticket that currently resides on this chain.
-    /// owner: wallet address of the current owner (must match ticket.owner)
+    /// Outside demo mode the block signer must be the current ticket owner or an approved operator.
     TransferTicket {
         ticket_id: TicketId,
-        buyer_chain: String,
-        new_owner: String,
+        buyer_chain: ChainId,
+        new_owner: AccountOwner,
         sale_price: Option<u128>,
     },
     /// Claims a ticket that resides on a remote chain.
+    /// The claimant (block signer, or `owner` in demo mode) must own the ticket or be an
+    /// approved operator.
     ClaimTicket {
-        source_chain: String,
+        source_chain: ChainId,
         ticket_id: TicketId,
-        new_owner: String,
+        new_owner: AccountOwner,
         sale_price: Option<u128>,
+        owner: Option<AccountOwner>,
     },
-    /// Create a marketplace listing for a ticket owned by the caller.
-    /// seller: wallet address of the seller (must match ticket.owner)
+    /// Create a marketplace listing for a ticket owned by the caller or one it operates.
+    /// seller: wallet address of the seller (demo mode; ticket.owner or an approved operator)
     CreateListing {
         ticket_id: TicketId,
         price: u128,
-        seller: String,
+        seller: Option<AccountOwner>,
     },
-    /// Cancel an existing listing (only seller).
-    /// seller: wallet address of the seller (must match listing.seller)
+    /// Cancel an existing listing (seller or an approved operator).
+    /// seller: wallet address of the seller (demo mode; listing.seller or an approved operator)
     CancelListing {
         ticket_id: TicketId,
-        seller: String,
+        seller: Option<AccountOwner>,
     },
     /// Buy an active listing.
-    /// buyer: wallet address of the buyer
+    /// buyer: wallet address of the buyer (demo mode)
     BuyListing {
         ticket_id: TicketId,
         price: u128,
-        buyer: String,
+        buyer: Option<AccountOwner>,
+    },
+    /// Destroys a ticket. Allowed for the holder (owner must match ticket.owner) or for the
+    /// organizer/event admins, who must give a reason. release_seat gives the seat and
+    /// capacity back to the event (organizer/event admins only).
+    BurnTicket {
+        ticket_id: TicketId,
+        owner: Option<AccountOwner>,
+        reason: Option<String>,
+        release_seat: bool,
+    },
+    /// Approves an operator to transfer, list and delist one ticket; None clears the approval.
+    /// The approval lapses when the ticket changes hands.
+    Approve {
+        ticket_id: TicketId,
+        operator: Option<AccountOwner>,
+        owner: Option<AccountOwner>,
+    },
+    /// Approves or revokes an operator for every ticket the owner holds on this chain,
+    /// current and future.
+    SetApprovalForAll {
+        operator: AccountOwner,
+        approved: bool,
+        owner: Option<AccountOwner>,
+    },
+    /// Turns attended tickets of an ended event into commemorative collectibles
+    /// (organizer or event admins only). royalty_bps replaces the ticket royalty on resales.
+    ConvertToCollectibles {
+        event_id: EventId,
+        image_url: Option<String>,
+        blob_hash: Option<DataBlobHash>,
+        royalty_bps: u16,
+    },
+    /// Checks a ticket in at the venue (organizer or scanner chains only).
+    /// Redeemed tickets can no longer be listed or transferred.
+    RedeemTicket {
+        ticket_id: TicketId,
+        gate: String,
+    },
+    /// Submits check-ins a scanner recorded while offline (organizer or scanner chains only).
+    /// The hub keeps the earliest scan of each ticket and flags the rest as fraud alerts.
+    SubmitCheckIns {
+        event_id: EventId,
+        check_ins: Vec<CheckIn>,
+    },
+    /// Grants a per-event role to a chain (organizer or event admin only).
+    GrantEventRole {
+        event_id: EventId,
+        chain: ChainId,
+        role: EventRole,
+    },
+    /// Revokes a per-event role from a chain (organizer or event admin only).
+    RevokeEventRole {
+        event_id: EventId,
+        chain: ChainId,
+        role: EventRole,
     },
     /// Subscribe to the hub chain's marketplace event stream.
-    /// This enables the user's chain to receive events, tickets, and listings from the hub.
+    /// This enables the user's chain to receive events, tickets, and listings from the hub,
+    /// and starts a paginated sync of the hub's existing state.
     SubscribeToHub,
+    /// Re-requests the pending page of an interrupted sync from the hub.
+    ResumeSync,
+    /// Imports records exported from a deployment that stored chains and owners as strings
+    /// (hub only, signed by the migration admin). May be sent in several batches;
+    /// records already imported are skipped.
+    ImportLegacyState {
+        events: Vec<migration::LegacyEvent>,
+        tickets: Vec<migration::LegacyTicket>,
+        listings: Vec<migration::LegacyListing>,
+        histories: Vec<migration::LegacyTicketHistory>,
+        balances: Vec<migration::LegacyBalance>,
+    },
 }
 
 /// Cross-chain messages emitted by the ticketing contract.
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Message {
-    /// Transfers a ticket to the target chain
+    /// Transfers a ticket to the target chain.
+    /// Sent with tracking by `transfer()`: if it bounces, the sender restores `previous_owner`
+    /// and `previous_sale_price`, takes back the sale's royalty and reopens `sold_listing`.
     Transfer {
         ticket: Ticket,
-        target_chain: String,
-        seller_chain: String,
+        target_chain: ChainId,
+        seller_chain: ChainId,
         sale_price: Option<u128>,
+        previous_owner: AccountOwner,
+        previous_sale_price: Option<u128>,
+        /// Marketplace listing the sale closed, if any
+        sold_listing: Option<Listing>,
     },
-    /// Requests a remote chain to release the ticket
+    /// Delivers a batch of freshly minted tickets to the owner chain
+    TransferBatch {
+        tickets: Vec<Ticket>,
+        target_chain: ChainId,
+        seller_chain: ChainId,
+    },
+    /// Requests a remote chain to release the ticket on behalf of `claimant`
     Claim {
-        source_chain: String,
+        source_chain: ChainId,
         ticket_id: TicketId,
-        requester_chain: String,
-        new_owner: String,
+        requester_chain: ChainId,
+        claimant: AccountOwner,
+        new_owner: AccountOwner,
         sale_price: Option<u128>,
     },
     
     // === Hub-bound messages (from user chains to marketplace chain) ===
     
-    /// Request initial state sync from hub (user chain → hub)
-    RequestSync {
-        requester_chain: String,
-    },
-    /// Initial state sync from hub (hub → user chain)
-    /// Sends ALL current events, tickets, and listings to the subscribing user
-    InitialStateSync {
-        events: Vec<Event>,
-        tickets: Vec<Ticket>,
-        listings: Vec<Listing>,
+    /// Request the next page of hub state after `cursor` (user chain → hub)
+    RequestSyncPage {
+        requester_chain: ChainId,
+        cursor: SyncCursor,
+    },
+    /// One page of hub state (hub → user chain), answering the request for `cursor`.
+    /// `next` is the cursor of the following page, None once every collection was sent.
+    /// `hub_sequence` is the last stream sequence the hub had emitted when serving the page.
+    SyncPage {
+        cursor: SyncCursor,
+        records: SyncRecords,
+        next: Option<SyncCursor>,
+        hub_sequence: u64,
     },
     /// Forward event creation to the hub
     CreateEventOnHub {
         event: Event,
     },
-    /// Forward listing creation to the hub
+    /// Forward listing creation to the hub. `seller` is the owner or an approved operator
+    /// listing from `seller_chain`; the hub lists for the owner it has on record.
     CreateListingOnHub {
-        listing: Listing,
+        ticket_id: TicketId,
+        seller_chain: ChainId,
+        seller: AccountOwner,
+        price: u128,
     },
     /// Forward listing cancellation to the hub
     CancelListingOnHub {
         ticket_id: TicketId,
-        seller_chain: String,
-        seller: String,
+        seller_chain: ChainId,
+        seller: AccountOwner,
     },
     /// Forward listing purchase to the hub
     BuyListingOnHub {
         ticket_id: TicketId,
-        buyer_chain: String,
-        buyer: String,
+        buyer_chain: ChainId,
+        buyer: AccountOwner,
         price: u128,
     },
     /// Forward mint ticket request to hub (hub does actual minting)
     MintTicketRequest {
-        minter_chain: String,
-        owner: String,
+        minter_chain: ChainId,
+        owner: AccountOwner,
         event_id: EventId,
         seat: String,
         blob_hash: DataBlobHash,
         // Wave 6: Enhanced metadata
         image_url: Option<String>,
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: Vec<MerkleHash>,
+        /// Whether the owner signed the mint, making `minter_chain` their own chain
+        owner_signed: bool,
+        /// ID of the optimistic copy stored on the minter chain, if any
+        pending_ticket_id: Option<TicketId>,
+    },
+    /// Forward batch mint request to hub (hub mints every seat in one go)
+    MintTicketBatchRequest {
+        minter_chain: ChainId,
+        owner: AccountOwner,
+        event_id: EventId,
+        seats: Vec<String>,
+        ranges: Vec<SeatRange>,
+        blob_hash: DataBlobHash,
+        image_url: Option<String>,
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: Vec<MerkleHash>,
+        /// Whether the owner signed the mint, making `minter_chain` their own chain
+        owner_signed: bool,
+        /// IDs of the optimistic copies stored on the minter chain, in seat order (or none)
+        pending_ticket_ids: Vec<TicketId>,
+    },
+    /// Forward presale changes to the hub
+    SetPresaleOnHub {
+        event_id: EventId,
+        requester_chain: ChainId,
+        presale: Option<Presale>,
+    },
+    /// Forward presale allowlist edits to the hub
+    UpdatePresaleAllowlistOnHub {
+        event_id: EventId,
+        requester_chain: ChainId,
+        added: Vec<HolderKey>,
+        removed: Vec<HolderKey>,
+    },
+    /// Forward holder limit changes to the hub
+    SetHolderLimitsOnHub {
+        event_id: EventId,
+        requester_chain: ChainId,
+        max_per_holder: Option<u32>,
+        max_purchases_per_holder: Option<u32>,
+    },
+    /// Forward ticket burning to the hub
+    BurnTicketOnHub {
+        ticket_id: TicketId,
+        requester_chain: ChainId,
+        owner: AccountOwner,
+        reason: Option<String>,
+        release_seat: bool,
+    },
+    /// Forward a per-ticket approval to the hub
+    ApproveOnHub {
+        ticket_id: TicketId,
+        requester_chain: ChainId,
+        owner: AccountOwner,
+        operator: Option<AccountOwner>,
+    },
+    /// Forward an operator approval for all of an owner's tickets to the hub
+    SetApprovalForAllOnHub {
+        requester_chain: ChainId,
+        owner: AccountOwner,
+        operator: AccountOwner,
+        approved: bool,
+    },
+    /// Tells the owner chain to drop a ticket burned on the hub
+    TicketBurned {
+        ticket_id: TicketId,
+    },
+    /// The hub accepted an optimistic write of the receiving chain
+    WriteConfirmed {
+        write: PendingWrite,
+    },
+    /// The hub refused an optimistic write of the receiving chain without failing the message
+    WriteRejected {
+        write: PendingWrite,
+    },
+    /// The hub minted the ticket stored optimistically as `pending_ticket_id` on the
+    /// receiving chain; `ticket` is the hub's copy, whose ID may differ
+    MintConfirmed {
+        pending_ticket_id: TicketId,
+        ticket: Ticket,
+    },
+    /// Forward collectible conversion to the hub
+    ConvertToCollectiblesOnHub {
+        event_id: EventId,
+        requester_chain: ChainId,
+        terms: CollectibleTerms,
+    },
+    /// Forward ticket redemption to the hub
+    RedeemTicketOnHub {
+        ticket_id: TicketId,
+        scanner_chain: ChainId,
+        gate: String,
+    },
+    /// Forward an offline check-in batch to the hub
+    SubmitCheckInsOnHub {
+        event_id: EventId,
+        scanner_chain: ChainId,
+        check_ins: Vec<CheckIn>,
+    },
+    /// Forward transfer lockout changes to the hub
+    SetTransferLockoutOnHub {
+        event_id: EventId,
+        requester_chain: ChainId,
+        lockout_secs: Option<u64>,
+    },
+    /// Forward role grant to the hub
+    GrantEventRoleOnHub {
+        event_id: EventId,
+        granter_chain: ChainId,
+        chain: ChainId,
+        role: EventRole,
+    },
+    /// Forward role revocation to the hub
+    RevokeEventRoleOnHub {
+        event_id: EventId,
+        granter_chain: ChainId,
+        chain: ChainId,
+        role: EventRole,
     },
     /// Mint ticket notification to hub (for tracking)
     MintTicketOnHub {
@@ -207,15 +613,18 @@ pub enum Message {
 #[serde(rename_all = "camelCase")]
 pub struct Event {
     pub id: EventId,
-    /// Stored as chain_id string for easy comparison
-    pub organizer_chain: String,
+    /// Organizer chain (holds every event role)
+    pub organizer_chain: ChainId,
     pub name: String,
     pub description: String,
     pub venue: String,
     pub start_time: u64,
     pub royalty_bps: u16,
     pub max_tickets: u32,
+    /// Mint counter; never decreases so ticket IDs are not reused
     pub minted_tickets: u32,
+    /// Burned tickets whose capacity was given back to the event
+    pub released_tickets: u32,
     // === Wave 6: Enhanced Metadata ===
     /// Optional image URL for the event
     pub image_url: Option<String>,
@@ -224,16 +633,129 @@ pub struct Event {
     /// Base price for tickets (for filtering) - stored as u128 but not exposed via GraphQL
     #[graphql(skip)]
     pub base_price: Option<u128>,
+    /// Primary sales open at this time (Unix seconds); None = open immediately
+    pub sale_start: Option<u64>,
+    /// Primary sales close at this time (Unix seconds); None = never close
+    pub sale_end: Option<u64>,
+    /// Secondary listings and purchases open at this time; None = open immediately
+    pub resale_start: Option<u64>,
+    /// Secondary listings and purchases close at this time; None = never close
+    pub resale_end: Option<u64>,
+    /// Max tickets one owner address or chain may hold at once; None = unlimited
+    pub max_per_holder: Option<u32>,
+    /// Max tickets one owner address or chain may mint or buy in total; None = unlimited
+    pub max_purchases_per_holder: Option<u32>,
+    /// Transfers and resales freeze this many seconds before start_time; None = never
+    pub transfer_lockout_secs: Option<u64>,
+    /// Default transferability of tickets minted for this event
+    pub transfer_policy: TransferPolicy,
+    /// Set once attended tickets were converted to collectibles
+    #[graphql(skip)]
+    pub collectible: Option<CollectibleTerms>,
+}
+
+/// Commemorative art and marketplace terms for attended tickets after the event.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectibleTerms {
+    pub image_url: Option<String>,
+    pub blob_hash: Option<DataBlobHash>,
+    /// Royalty applied to collectible resales
+    pub royalty_bps: u16,
+    /// When the conversion ran (Unix ms)
+    pub converted_at: u64,
+}
+
+impl Event {
+    /// Returns true once the event is over at `now` (Unix seconds): after end_time,
+    /// or after start_time when no end_time is set.
+    pub fn has_ended(&self, now: u64) -> bool {
+        now >= self.end_time.unwrap_or(self.start_time).max(self.start_time)
+    }
+
+    /// Returns true if `count` more tickets fit under max_tickets (released capacity counts as free).
+    pub fn has_capacity_for(&self, count: usize) -> bool {
+        let live = self.minted_tickets.saturating_sub(self.released_tickets) as usize;
+        live + count <= self.max_tickets as usize
+    }
+
+    /// Returns true if the organizer capped holdings or purchases per holder.
+    pub fn has_holder_limits(&self) -> bool {
+        self.max_per_holder.is_some() || self.max_purchases_per_holder.is_some()
+    }
+
+    /// Returns true if primary minting is allowed at `now` (Unix seconds).
+    pub fn is_on_sale(&self, now: u64) -> bool {
+        is_within_window(self.sale_start, self.sale_end, now)
+    }
+
+    /// Returns the `(locked_from, unlocks_at)` window (Unix seconds) of the transfer lockout.
+    /// Tickets stay frozen from the lockout start until the event ends (or starts, without end_time).
+    pub fn transfer_lockout(&self) -> Option<(u64, u64)> {
+        let lockout = self.transfer_lockout_secs?;
+        let locked_from = self.start_time.saturating_sub(lockout);
+        let unlocks_at = self.end_time.unwrap_or(self.start_time).max(self.start_time);
+        Some((locked_from, unlocks_at))
+    }
+
+    /// Returns when transfers unlock again if they are locked at `now` (Unix seconds).
+    pub fn transfer_locked_until(&self, now: u64) -> Option<u64> {
+        self.transfer_lockout()
+            .filter(|&(locked_from, unlocks_at)| is_within_window(Some(locked_from), Some(unlocks_at), now))
+            .map(|(_, unlocks_at)| unlocks_at)
+    }
+
+    /// Returns true if secondary listings and purchases are allowed at `now` (Unix seconds).
+    pub fn is_resale_open(&self, now: u64) -> bool {
+        is_within_window(self.resale_start, self.resale_end, now)
+    }
+}
+
+/// Half-open window check: `start <= now < end`, with missing bounds left open.
+fn is_within_window(start: Option<u64>, end: Option<u64>, now: u64) -> bool {
+    start.is_none_or(|start| now >= start) && end.is_none_or(|end| now < end)
+}
+
+/// Delegated per-event permissions. The organizer chain implicitly holds every role,
+/// and `Admin` implies all other roles.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, async_graphql::Enum,
+)]
+pub enum EventRole {
+    /// Manages the event and grants non-admin roles
+    Admin,
+    /// Mints tickets for the event
+    Minter,
+    /// Checks tickets in at the venue
+    Scanner,
+}
+
+/// Returns true if a set of delegated roles grants `role` (admins inherit all non-admin roles).
+pub fn roles_grant(roles: &BTreeSet<EventRole>, role: EventRole) -> bool {
+    roles.contains(&role) || (role != EventRole::Admin && roles.contains(&EventRole::Admin))
+}
+
+/// A holder counted for per-event holder limits.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HolderKey {
+    Owner(AccountOwner),
+    Chain(ChainId),
+}
+
+/// Keys a ticket holder is counted under for per-event holder limits:
+/// the owner account and the owner chain.
+pub fn holder_keys(owner: &AccountOwner, owner_chain: &ChainId) -> [HolderKey; 2] {
+    [HolderKey::Owner(*owner), HolderKey::Chain(*owner_chain)]
 }
 
 /// A record of ownership for a ticket (Wave 6: Provenance tracking)
 #[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct OwnershipRecord {
-    /// Owner wallet address
-    pub owner: String,
+    /// Owner account
+    pub owner: AccountOwner,
     /// Owner's chain ID
-    pub owner_chain: String,
+    pub owner_chain: ChainId,
     /// Timestamp when ownership was acquired (Unix ms)
     pub acquired_at: u64,
     /// Price paid as string (None for minted tickets)
@@ -247,6 +769,8 @@ pub enum AcquisitionType {
     Minted,
     Purchased,
     Transferred,
+    /// Back with the previous owner after a transfer bounced
+    Returned,
 }
 
 /// Price history entry for a ticket (Wave 6)
@@ -276,6 +800,111 @@ pub struct TicketHistory {
     pub ownership_history: Vec<OwnershipRecord>,
     /// All price events in chronological order
     pub price_history: Vec<PriceHistoryEntry>,
+    /// Venue check-ins in chronological order
+    pub check_ins: Vec<Redemption>,
+}
+
+/// A check-in recorded by a scanner device, possibly while offline.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckIn {
+    pub ticket_id: TicketId,
+    pub gate: String,
+    /// Device time of the scan (Unix ms)
+    pub scanned_at: u64,
+}
+
+/// Raised when a ticket was checked in more than once.
+#[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FraudAlert {
+    pub ticket_id: TicketId,
+    pub event_id: EventId,
+    /// The earliest scan, which stands as the ticket's redemption
+    pub accepted: Redemption,
+    /// The later duplicate scan
+    pub rejected: Redemption,
+    /// When the hub detected the duplicate (Unix ms)
+    pub detected_at: u64,
+}
+
+/// Who may move a ticket to a new owner.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+pub enum TransferPolicy {
+    /// Transfers, claims and marketplace resales are all allowed
+    #[default]
+    Free,
+    /// The ticket only changes hands through a marketplace purchase
+    ResaleOnly,
+    /// The ticket never leaves its first owner (ID-checked events)
+    Soulbound,
+}
+
+impl TransferPolicy {
+    /// Returns true if the ticket may move to a new owner this way.
+    pub fn allows_transfer(self, through_marketplace: bool) -> bool {
+        match self {
+            Self::Free => true,
+            Self::ResaleOnly => through_marketplace,
+            Self::Soulbound => false,
+        }
+    }
+
+    /// Returns true if the ticket may be listed on the marketplace.
+    pub fn allows_listing(self) -> bool {
+        self != Self::Soulbound
+    }
+
+    /// Returns true if this policy restricts transfers at least as much as `other`,
+    /// so it may override `other` for a single ticket.
+    pub fn is_at_least_as_strict_as(self, other: Self) -> bool {
+        self.strictness() >= other.strictness()
+    }
+
+    fn strictness(self) -> u8 {
+        match self {
+            Self::Free => 0,
+            Self::ResaleOnly => 1,
+            Self::Soulbound => 2,
+        }
+    }
+}
+
+/// Lifecycle state of a ticket.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+pub enum TicketStatus {
+    /// Not yet used; can be listed and transferred
+    #[default]
+    Valid,
+    /// Checked in at the venue; frozen for listings and transfers
+    Redeemed,
+    /// Attended ticket converted to a commemorative collectible after the event
+    Collectible,
+}
+
+/// Display state of a ticket, telling unused-expired tickets apart from collectibles.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+pub enum TicketLifecycle {
+    /// Unused and the event has not ended
+    Valid,
+    /// Checked in, not (yet) converted
+    Redeemed,
+    /// Never used and the event is over
+    Expired,
+    /// Attended and converted to a collectible
+    Collectible,
+}
+
+/// A venue check-in of a ticket.
+#[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Redemption {
+    /// Timestamp of the check-in (Unix ms)
+    pub redeemed_at: u64,
+    /// Gate or entrance where the ticket was scanned
+    pub gate: String,
+    /// Chain that submitted the check-in
+    pub scanner_chain: ChainId,
 }
 
 /// Ticket metadata alongside royalty bookkeeping.
@@ -287,21 +916,78 @@ pub struct Ticket {
     pub event_name: String,
     pub seat: String,
     /// Organizer chain for royalty distribution
-    pub organizer_chain: String,
+    pub organizer_chain: ChainId,
     /// Current owner chain
-    pub owner_chain: String,
-    /// Current owner address (wallet address for demo mode)
-    pub owner: String,
+    pub owner_chain: ChainId,
+    /// Current owner account
+    pub owner: AccountOwner,
     /// Minter chain
-    pub minter_chain: String,
+    pub minter_chain: ChainId,
     pub royalty_bps: u16,
     pub metadata_hash: DataBlobHash,
     pub last_sale_price: Option<u128>,
     // === Wave 6: Enhanced Metadata ===
     /// Optional image URL for the ticket
     pub image_url: Option<String>,
+    /// Who may move this ticket (resolved from the event or tier at mint)
+    pub transfer_policy: TransferPolicy,
     /// Timestamp when ticket was minted (Unix ms)
     pub minted_at: u64,
+    pub status: TicketStatus,
+    /// Set once the ticket is checked in at the venue
+    pub redemption: Option<Redemption>,
+    /// Event mint counter used when deriving the ticket ID
+    pub mint_index: u32,
+}
+
+/// Operator allowed to move a single ticket on behalf of its owner.
+/// Only honored while `owner` still holds the ticket.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, SimpleObject, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TicketApproval {
+    /// Ticket owner who granted the approval
+    pub owner: AccountOwner,
+    /// Approved operator account
+    pub operator: AccountOwner,
+}
+
+/// Record written on a user chain before the hub accepted it.
+/// Forwarded with tracking: the hub confirms it, or the message bounces and the copy is rolled back.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PendingWrite {
+    Event(EventId),
+    Ticket(TicketId),
+    /// Listing of the given ticket
+    Listing(TicketId),
+}
+
+impl PendingWrite {
+    pub fn kind(&self) -> WriteKind {
+        match self {
+            PendingWrite::Event(_) => WriteKind::Event,
+            PendingWrite::Ticket(_) => WriteKind::Ticket,
+            PendingWrite::Listing(_) => WriteKind::Listing,
+        }
+    }
+}
+
+/// Kind of record an optimistic write created.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+pub enum WriteKind {
+    Event,
+    Ticket,
+    Listing,
+}
+
+/// Hub outcome of an optimistic write.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+pub enum WriteStatus {
+    /// Stored locally and forwarded; the hub has not answered yet
+    Pending,
+    /// Accepted by the hub
+    Confirmed,
+    /// Rejected by the hub; the local copy was rolled back
+    Rejected,
 }
 
 /// Marketplace listing for a ticket.
@@ -310,9 +996,9 @@ pub struct Ticket {
 pub struct Listing {
     pub ticket_id: TicketId,
     /// Seller chain
-    pub seller_chain: String,
-    /// Seller address (wallet address for demo mode)
-    pub seller: String,
+    pub seller_chain: ChainId,
+    /// Seller account
+    pub seller: AccountOwner,
     pub price: u128,
     pub status: ListingStatus,
 }
@@ -324,6 +1010,17 @@ pub enum ListingStatus {
     Sold,
 }
 
+/// Outcome of loading a ticket's metadata blob.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+pub enum MetadataStatus {
+    /// Blob loaded and decoded as JSON
+    Ok,
+    /// Blob loaded but is not valid JSON (raw bytes are still returned)
+    InvalidJson,
+    /// No metadata blob is referenced (placeholder hash), or it is not known to be published
+    Missing,
+}
+
 /// Human-friendly output of a ticket (with payload bytes).
 #[derive(Debug, Serialize, Deserialize, Clone, SimpleObject, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -332,22 +1029,42 @@ pub struct TicketOutput {
     pub event_id: EventId,
     pub event_name: String,
     pub seat: String,
-    pub organizer_chain: String,
-    pub owner_chain: String,
-    pub owner: String,
-    pub minter_chain: String,
+    pub organizer_chain: ChainId,
+    pub owner_chain: ChainId,
+    pub owner: AccountOwner,
+    pub minter_chain: ChainId,
     pub royalty_bps: u16,
-    pub payload: Vec<u8>,
+    /// Raw bytes of the metadata blob (null when missing)
+    pub payload: Option<Vec<u8>>,
+    /// Metadata blob decoded as JSON, when it parses
+    pub metadata: Option<async_graphql::Json<serde_json::Value>>,
+    pub metadata_status: MetadataStatus,
+    /// Hex hash of the referenced metadata blob
+    pub metadata_hash: String,
     pub last_sale_price: Option<String>,
     // === Wave 6: Enhanced Metadata ===
     pub image_url: Option<String>,
     pub minted_at: String,
+    /// Wallets hide transfer/list buttons based on this
+    pub transfer_policy: TransferPolicy,
+    pub status: TicketStatus,
+    pub redemption: Option<Redemption>,
+    /// Status refined with the event's end time (see `TicketOutput::with_event`)
+    pub lifecycle: TicketLifecycle,
 }
 
 impl TicketOutput {
-    pub fn new(ticket: Ticket, payload: Vec<u8>) -> Self {
+    /// `payload` is the metadata blob content, or None when no blob is referenced.
+    pub fn new(ticket: Ticket, payload: Option<Vec<u8>>) -> Self {
         use base64::engine::{general_purpose::STANDARD_NO_PAD, Engine as _};
         let ticket_id = STANDARD_NO_PAD.encode(ticket.ticket_id.id.clone());
+        let (metadata, metadata_status) = match &payload {
+            None => (None, MetadataStatus::Missing),
+            Some(bytes) => match serde_json::from_slice(bytes) {
+                Ok(value) => (Some(async_graphql::Json(value)), MetadataStatus::Ok),
+                Err(_) => (None, MetadataStatus::InvalidJson),
+            },
+        };
         Self {
             ticket_id,
             event_id: ticket.event_id,
@@ -359,10 +1076,91 @@ impl TicketOutput {
             minter_chain: ticket.minter_chain,
             royalty_bps: ticket.royalty_bps,
             payload,
+            metadata,
+            metadata_status,
+            metadata_hash: ticket.metadata_hash.0.to_string(),
             last_sale_price: ticket.last_sale_price.map(|p| p.to_string()),
             image_url: ticket.image_url,
             minted_at: ticket.minted_at.to_string(),
+            transfer_policy: ticket.transfer_policy,
+            status: ticket.status,
+            redemption: ticket.redemption,
+            lifecycle: match ticket.status {
+                TicketStatus::Valid => TicketLifecycle::Valid,
+                TicketStatus::Redeemed => TicketLifecycle::Redeemed,
+                TicketStatus::Collectible => TicketLifecycle::Collectible,
+            },
+        }
+    }
+
+    /// Marks unused tickets of an event that is over at `now` (Unix seconds) as expired.
+    pub fn with_event(mut self, event: Option<&Event>, now: u64) -> Self {
+        if self.lifecycle == TicketLifecycle::Valid && event.is_some_and(|event| event.has_ended(now)) {
+            self.lifecycle = TicketLifecycle::Expired;
+        }
+        self
+    }
+}
+
+/// Returns true if the hash is the all-zero placeholder used when no metadata was uploaded.
+pub fn is_placeholder_blob(hash: &DataBlobHash) -> bool {
+    hash.0.as_bytes().iter().all(|byte| *byte == 0)
+}
+
+/// Ticket metadata blob schema (JSON):
+///
+/// ```json
+/// {
+///   "name": "Summer Fest – Day 1",
+///   "seat": "A-12",
+///   "attributes": [{ "trait_type": "Tier", "value": "VIP" }],
+///   "image": "https://example.com/ticket.png"
+/// }
+/// ```
+///
+/// `name` is required and non-empty; `seat`, `attributes` and `image` are optional.
+/// Unknown fields are ignored.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct TicketMetadata {
+    pub name: String,
+    #[serde(default)]
+    pub seat: Option<String>,
+    #[serde(default)]
+    pub attributes: Vec<MetadataAttribute>,
+    #[serde(default)]
+    pub image: Option<String>,
+}
+
+/// A single trait of a ticket (e.g. tier, gate, day)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct MetadataAttribute {
+    pub trait_type: String,
+    pub value: serde_json::Value,
+}
+
+impl TicketMetadata {
+    /// Parses and validates a metadata blob against the schema.
+    pub fn parse(bytes: &[u8]) -> Result<Self, String> {
+        let metadata: TicketMetadata =
+            serde_json::from_slice(bytes).map_err(|error| format!("invalid metadata: {}", error))?;
+        if metadata.name.trim().is_empty() {
+            return Err("metadata name is empty".to_string());
         }
+        if metadata
+            .attributes
+            .iter()
+            .any(|attribute| attribute.trait_type.trim().is_empty())
+        {
+            return Err("metadata attribute without trait_type".to_string());
+        }
+        Ok(metadata)
+    }
+
+    /// Returns true if the metadata names no seat or the same seat (ignoring case and spaces).
+    pub fn matches_seat(&self, seat: &str) -> bool {
+        self.seat
+            .as_deref()
+            .is_none_or(|own| normalize_seat(own) == normalize_seat(seat))
     }
 }
 
@@ -397,7 +1195,7 @@ impl Ticket {
         application_id: &ApplicationId,
         event_id: &EventId,
         seat: &str,
-        minter_chain: &str,
+        minter_chain: &ChainId,
         metadata_hash: &DataBlobHash,
         event_mint_index: u32,
     ) -> Result<TicketId, bcs::Error> {
@@ -407,7 +1205,8 @@ impl Ticket {
         hasher.update(application_id.to_bcs_bytes()?);
         hasher.update(event_id.value.as_bytes());
         hasher.update(seat.as_bytes());
-        hasher.update(minter_chain.as_bytes());
+        // Hashed in its string form so IDs match tickets minted before chains were typed
+        hasher.update(minter_chain.to_string().as_bytes());
         hasher.update(metadata_hash.to_bcs_bytes()?);
         hasher.update(event_mint_index.to_bcs_bytes()?);
 
@@ -417,16 +1216,381 @@ impl Ticket {
     }
 }
 
+/// Hub collections copied to user chains by paginated sync, in the order they are sent.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, async_graphql::Enum,
+)]
+pub enum SyncCollection {
+    Events,
+    Tickets,
+    Listings,
+}
+
+impl SyncCollection {
+    /// The collection sent after this one, if any.
+    pub fn next(self) -> Option<Self> {
+        match self {
+            SyncCollection::Events => Some(SyncCollection::Tickets),
+            SyncCollection::Tickets => Some(SyncCollection::Listings),
+            SyncCollection::Listings => None,
+        }
+    }
+}
+
+/// Position in the hub's sync order: a collection, and how far its change log was walked
+/// (None to start at the first change after `since`).
+/// Each collection's change log lists its keys in the order the hub changed them.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SyncCursor {
+    pub collection: SyncCollection,
+    pub position: Option<u64>,
+    /// Only records changed after this hub stream sequence (None for a full sync)
+    pub since: Option<u64>,
+}
+
+impl SyncCursor {
+    /// Cursor of the first page of a collection.
+    pub fn start(collection: SyncCollection, since: Option<u64>) -> Self {
+        Self { collection, position: None, since }
+    }
+}
+
+/// Records of one sync page. Delta pages also list records deleted since the cursor's sequence.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum SyncRecords {
+    Events(Vec<Event>),
+    Tickets {
+        tickets: Vec<Ticket>,
+        removed: Vec<TicketId>,
+    },
+    Listings {
+        listings: Vec<Listing>,
+        removed: Vec<TicketId>,
+    },
+}
+
+impl SyncRecords {
+    pub fn len(&self) -> usize {
+        match self {
+            SyncRecords::Events(events) => events.len(),
+            SyncRecords::Tickets { tickets, removed } => tickets.len() + removed.len(),
+            SyncRecords::Listings { listings, removed } => listings.len() + removed.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Progress of a user chain's paginated sync from the hub.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// Cursor of the page to request next; None when no sync is running
+    pub next: Option<SyncCursor>,
+    /// Pages applied since the sync started
+    pub pages_received: u32,
+    /// Set once the last page was applied
+    pub completed: bool,
+    /// Hub stream sequence when the first page was served; every page reflects the events
+    /// up to it
+    pub base_sequence: u64,
+}
+
+/// Selects the keys of one sync page while a collection's change log is walked from the cursor.
+/// Log entries pair a key with the hub sequence that changed it; a key changed several times
+/// is only sent at its latest change.
+pub struct SyncPager<K> {
+    collection: SyncCollection,
+    since: Option<u64>,
+    position: u64,
+    scanned: usize,
+    keys: Vec<K>,
+}
+
+impl<K> SyncPager<K> {
+    /// Starts walking `cursor`'s collection at log position `start`.
+    pub fn new(cursor: &SyncCursor, start: u64) -> Self {
+        Self {
+            collection: cursor.collection,
+            since: cursor.since,
+            position: start,
+            scanned: 0,
+            keys: Vec::new(),
+        }
+    }
+
+    /// Log range to read next, or None once the page is full, the scan limit is reached
+    /// or the log is exhausted.
+    pub fn next_range(&self, log_len: u64) -> Option<std::ops::Range<usize>> {
+        if self.keys.len() == SYNC_PAGE_SIZE || self.scanned == SYNC_SCAN_LIMIT {
+            return None;
+        }
+        let start = self.position as usize;
+        let budget = (SYNC_SCAN_LIMIT - self.scanned).min(SYNC_PAGE_SIZE);
+        let end = (log_len as usize).min(start + budget);
+        (start < end).then_some(start..end)
+    }
+
+    /// Offers the next log entry, with the sequence of the key's latest change;
+    /// returns false, leaving the entry for the next page, once the page is full.
+    pub fn offer(&mut self, sequence: u64, key: K, latest: Option<u64>) -> bool {
+        if self.keys.len() == SYNC_PAGE_SIZE || self.scanned == SYNC_SCAN_LIMIT {
+            return false;
+        }
+        self.position += 1;
+        self.scanned += 1;
+        if latest == Some(sequence) {
+            self.keys.push(key);
+        }
+        true
+    }
+
+    /// Returns the page keys and the cursor that follows them (None after the last collection).
+    pub fn finish(self, log_len: u64) -> (Vec<K>, Option<SyncCursor>) {
+        let next = if self.position < log_len {
+            Some(SyncCursor {
+                collection: self.collection,
+                position: Some(self.position),
+                since: self.since,
+            })
+        } else {
+            self.collection.next().map(|next| SyncCursor::start(next, self.since))
+        };
+        (self.keys, next)
+    }
+}
+
+/// A marketplace stream entry: the event and its hub-assigned sequence.
+/// Sequences start at 1 and increase by one per emitted event.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SequencedEvent {
+    pub sequence: u64,
+    pub event: StreamEvent,
+}
+
 /// Event values emitted to the marketplace stream for cross-chain sync.
 /// User chains subscribe to this stream to receive updates from the hub.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum StreamEvent {
     /// A new event was created on the hub
     EventCreated { event: Event },
+    /// An event's settings were changed on the hub
+    EventUpdated { event: Event },
     /// A ticket was minted on the hub
     TicketMinted { ticket: Ticket },
+    /// A batch of tickets was minted on the hub
+    TicketsMinted { tickets: Vec<Ticket> },
     /// A listing was created on the hub  
     ListingCreated { listing: Listing },
     /// A listing was updated (cancelled/sold) on the hub
     ListingUpdated { listing: Listing },
+    /// A ticket was destroyed on the hub
+    TicketBurned {
+        ticket_id: TicketId,
+        event_id: EventId,
+        owner_chain: ChainId,
+        /// Seat label given back to the event, if any
+        released_seat: Option<String>,
+        reason: Option<String>,
+    },
+    /// Attended tickets of an event were converted to collectibles
+    CollectiblesConverted {
+        event_id: EventId,
+        terms: CollectibleTerms,
+        ticket_ids: Vec<TicketId>,
+    },
+    /// A ticket was checked in at the venue
+    TicketRedeemed {
+        ticket_id: TicketId,
+        redemption: Redemption,
+    },
+    /// A duplicate check-in was detected on the hub
+    FraudAlertRaised { alert: FraudAlert },
+    /// An event's presale was set, edited or cleared on the hub
+    PresaleUpdated {
+        event_id: EventId,
+        presale: Option<Presale>,
+    },
+    /// The roles held by a chain for an event changed on the hub
+    EventRolesUpdated {
+        event_id: EventId,
+        chain: ChainId,
+        roles: Vec<EventRole>,
+    },
+    /// A ticket changed hands on the hub; `ticket` carries the new owner
+    TicketTransferred {
+        ticket: Ticket,
+        previous_owner: AccountOwner,
+        previous_chain: ChainId,
+        sale_price: Option<u128>,
+        /// When the transfer happened (Unix ms)
+        transferred_at: u64,
+    },
+    /// A resale credited royalties to the organizer chain
+    RoyaltyAccrued {
+        organizer_chain: ChainId,
+        ticket_id: TicketId,
+        amount: u128,
+    },
+    /// A resale whose delivery bounced gave back the royalty it had accrued
+    RoyaltyReversed {
+        organizer_chain: ChainId,
+        ticket_id: TicketId,
+        amount: u128,
+    },
+}
+
+impl StreamEvent {
+    /// Returns true if the event only stores records copied by paginated sync
+    /// (events and listings), so a completed sync already reflects it.
+    /// Other events also update derived state (ownership, holder counts, history, royalties,
+    /// seats, roles) that pages do not carry, and are always applied.
+    pub fn is_covered_by_sync(&self) -> bool {
+        matches!(
+            self,
+            StreamEvent::EventCreated { .. }
+                | StreamEvent::EventUpdated { .. }
+                | StreamEvent::ListingCreated { .. }
+                | StreamEvent::ListingUpdated { .. }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn range(row: &str, from: u32, to: u32) -> SeatRange {
+        SeatRange { row: row.to_string(), from, to }
+    }
+
+    #[test]
+    fn normalize_seat_ignores_case_and_whitespace() {
+        assert_eq!(normalize_seat(" a12 "), Some("A12".to_string()));
+        assert_eq!(normalize_seat("A12"), normalize_seat("a12"));
+    }
+
+    #[test]
+    fn normalize_seat_leaves_general_admission_unreserved() {
+        assert_eq!(normalize_seat(""), None);
+        assert_eq!(normalize_seat("   "), None);
+        assert_eq!(normalize_seat("ga"), None);
+        assert_eq!(normalize_seat("GA1"), Some("GA1".to_string()));
+    }
+
+    #[test]
+    fn transfer_policy_overrides_may_only_tighten() {
+        use TransferPolicy::*;
+        assert!(Soulbound.is_at_least_as_strict_as(Free));
+        assert!(ResaleOnly.is_at_least_as_strict_as(ResaleOnly));
+        assert!(!Free.is_at_least_as_strict_as(Soulbound));
+        assert!(!ResaleOnly.is_at_least_as_strict_as(Soulbound));
+    }
+
+    #[test]
+    fn expand_seats_appends_ranges_to_the_list() {
+        let seats = vec!["VIP".to_string()];
+        let expanded = expand_seats(&seats, &[range("A", 1, 3), range("B", 7, 7)]);
+        assert_eq!(expanded, ["VIP", "A1", "A2", "A3", "B7"]);
+    }
+
+    #[test]
+    fn batch_size_counts_without_expanding() {
+        assert_eq!(batch_size(&[], &[range("A", 0, u32::MAX)]), Some(1 << 32));
+        assert_eq!(batch_size(&["X".to_string()], &[range("A", 5, 9)]), Some(6));
+        assert_eq!(batch_size(&[], &[range("A", 9, 5)]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "seat range ends before it starts")]
+    fn expand_seats_rejects_reversed_ranges() {
+        expand_seats(&[], &[range("A", 2, 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "batch exceeds")]
+    fn expand_seats_rejects_huge_ranges_before_expanding() {
+        expand_seats(&[], &[range("A", 0, u32::MAX)]);
+    }
+
+    /// Walks a change log of `(sequence, key)` entries like the hub does for one page.
+    fn walk(
+        log: &[(u64, u32)],
+        latest: &BTreeMap<u32, u64>,
+        cursor: &SyncCursor,
+    ) -> (Vec<u32>, Option<SyncCursor>) {
+        let mut pager = SyncPager::new(cursor, cursor.position.unwrap_or(0));
+        while let Some(range) = pager.next_range(log.len() as u64) {
+            for &(sequence, key) in &log[range] {
+                if !pager.offer(sequence, key, latest.get(&key).copied()) {
+                    break;
+                }
+            }
+        }
+        pager.finish(log.len() as u64)
+    }
+
+    #[test]
+    fn sync_pager_sends_keys_at_their_latest_change_only() {
+        let log = [(1, 7), (2, 8), (3, 7)];
+        let latest = BTreeMap::from([(7, 3), (8, 2)]);
+        let (keys, next) = walk(&log, &latest, &SyncCursor::start(SyncCollection::Events, None));
+        assert_eq!(keys, vec![8, 7]);
+        assert_eq!(next, Some(SyncCursor::start(SyncCollection::Tickets, None)));
+    }
+
+    #[test]
+    fn sync_pager_resumes_where_a_full_page_stopped() {
+        let count = SYNC_PAGE_SIZE as u32 + 5;
+        let log: Vec<(u64, u32)> = (0..count).map(|key| (key as u64 + 1, key)).collect();
+        let latest = log.iter().map(|&(sequence, key)| (key, sequence)).collect();
+        let start = SyncCursor::start(SyncCollection::Listings, Some(0));
+        let (keys, next) = walk(&log, &latest, &start);
+        assert_eq!(keys.len(), SYNC_PAGE_SIZE);
+        let next = next.unwrap();
+        assert_eq!(next.position, Some(SYNC_PAGE_SIZE as u64));
+        assert_eq!(next.since, Some(0));
+
+        let (keys, next) = walk(&log, &latest, &next);
+        assert_eq!(keys, (SYNC_PAGE_SIZE as u32..count).collect::<Vec<_>>());
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn sync_pager_bounds_the_entries_walked_per_page() {
+        // One key changed over and over: only its last entry is sent, over several pages
+        let last = SYNC_SCAN_LIMIT as u64 + 1;
+        let log: Vec<(u64, u32)> = (1..=last).map(|sequence| (sequence, 7)).collect();
+        let latest = BTreeMap::from([(7, last)]);
+        let (keys, next) = walk(&log, &latest, &SyncCursor::start(SyncCollection::Tickets, None));
+        assert!(keys.is_empty());
+        let next = next.unwrap();
+        assert_eq!(next.position, Some(SYNC_SCAN_LIMIT as u64));
+
+        let (keys, _) = walk(&log, &latest, &next);
+        assert_eq!(keys, vec![7]);
+    }
+
+    #[test]
+    fn resolved_event_ids_skip_taken_nonces() {
+        use linera_sdk::{linera_base_types::CryptoHash, util::BlockingWait};
+
+        let organizer = ChainId(CryptoHash::test_hash("organizer"));
+        let taken = BTreeSet::from([
+            EventId::scoped(&organizer, "3"),
+            EventId::scoped(&organizer, "4"),
+        ]);
+        let is_taken = |candidate: EventId| std::future::ready(taken.contains(&candidate));
+
+        let (event_id, next) = EventId::resolve(&organizer, "", 3, is_taken).blocking_wait();
+        assert_eq!(event_id, EventId::scoped(&organizer, "5"));
+        assert_eq!(next, Some(6));
+
+        let (event_id, next) = EventId::resolve(&organizer, " gig ", 3, is_taken).blocking_wait();
+        assert_eq!(event_id, EventId::scoped(&organizer, "gig"));
+        assert_eq!(next, None);
+    }
 }
@@ -0,0 +1,365 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/*! Import of state written before chain IDs and owners were typed.
+ *
+ *  Older deployments stored chains and wallet addresses as plain strings, which the
+ *  typed views cannot read. Migration flow:
+ *  - Export events, tickets, listings, histories and royalty balances from the old
+ *    application's storage. The `Legacy*` types below mirror its stored records; its
+ *    GraphQL output is not enough, as it encodes ticket IDs in base64 and amounts and
+ *    timestamps as strings, and leaves out base prices and metadata hashes
+ *  - Fill in `LegacyTicket::mint_index` with `recover_mint_index`, since older versions
+ *    did not store the mint counter a ticket ID was derived from
+ *  - Deploy the new application with `migration_admin` set in its parameters
+ *  - Send the records to the new hub in `Operation::ImportLegacyState` batches,
+ *    signed by the migration admin. Records already on the hub are skipped, so a batch
+ *    can be sent again after a failure
+ *
+ *  Ticket IDs are kept, so links to existing tickets stay valid. They were derived from
+ *  the old application ID, so offline entry passes (`EntryPassVerifier`) only verify for
+ *  tickets minted by the new deployment.
+ */
+
+use std::fmt;
+
+use linera_sdk::linera_base_types::{AccountOwner, ApplicationId, ChainId, DataBlobHash};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AcquisitionType, Event, EventId, Listing, ListingStatus, OwnershipRecord, PriceHistoryEntry,
+    Ticket, TicketHistory, TicketId, TicketStatus, TransferPolicy,
+};
+
+/// Event as stored before chains were typed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyEvent {
+    pub id: EventId,
+    pub organizer_chain: String,
+    pub name: String,
+    pub description: String,
+    pub venue: String,
+    pub start_time: u64,
+    pub royalty_bps: u16,
+    pub max_tickets: u32,
+    pub minted_tickets: u32,
+    pub image_url: Option<String>,
+    pub end_time: Option<u64>,
+    pub base_price: Option<u128>,
+}
+
+/// Ticket as stored before chains and owners were typed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyTicket {
+    pub ticket_id: TicketId,
+    pub event_id: EventId,
+    pub event_name: String,
+    pub seat: String,
+    pub organizer_chain: String,
+    pub owner_chain: String,
+    pub owner: String,
+    pub minter_chain: String,
+    pub royalty_bps: u16,
+    pub metadata_hash: DataBlobHash,
+    pub last_sale_price: Option<u128>,
+    pub image_url: Option<String>,
+    pub minted_at: u64,
+    /// Not stored by older versions - see `recover_mint_index`
+    pub mint_index: u32,
+}
+
+/// Listing as stored before chains and owners were typed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyListing {
+    pub ticket_id: TicketId,
+    pub seller_chain: String,
+    pub seller: String,
+    pub price: u128,
+    pub status: ListingStatus,
+}
+
+/// Ownership record as stored before chains and owners were typed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyOwnershipRecord {
+    pub owner: String,
+    pub owner_chain: String,
+    pub acquired_at: u64,
+    pub price_paid: Option<String>,
+    pub acquisition_type: AcquisitionType,
+}
+
+/// Ticket history as stored before chains and owners were typed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyTicketHistory {
+    pub ticket_id: TicketId,
+    pub ownership_history: Vec<LegacyOwnershipRecord>,
+    pub price_history: Vec<PriceHistoryEntry>,
+}
+
+/// Royalty balance of a chain as stored before chains were typed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyBalance {
+    pub chain: String,
+    pub pending: u128,
+}
+
+/// Reasons a legacy record cannot be converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationError {
+    /// A chain ID string is not a valid `ChainId`
+    InvalidChain(String),
+    /// A wallet address string is not a valid `AccountOwner`
+    InvalidOwner(String),
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChain(chain) => write!(f, "invalid chain ID '{}'", chain),
+            Self::InvalidOwner(owner) => write!(f, "invalid owner address '{}'", owner),
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+/// Parses a legacy chain ID string.
+pub fn parse_chain(chain: &str) -> Result<ChainId, MigrationError> {
+    chain
+        .trim()
+        .parse()
+        .map_err(|_| MigrationError::InvalidChain(chain.to_string()))
+}
+
+/// Parses a legacy wallet address, normalizing case as the old string comparisons did.
+pub fn parse_owner(owner: &str) -> Result<AccountOwner, MigrationError> {
+    owner
+        .trim()
+        .to_lowercase()
+        .parse()
+        .map_err(|_| MigrationError::InvalidOwner(owner.to_string()))
+}
+
+/// Finds the event mint counter a legacy ticket ID was derived from, given the old hub
+/// chain and application ID. Hashes up to `minted_tickets` candidates, so run it while
+/// exporting rather than on chain.
+pub fn recover_mint_index(
+    ticket: &LegacyTicket,
+    hub_chain: &ChainId,
+    application_id: &ApplicationId,
+    minted_tickets: u32,
+) -> Option<u32> {
+    let minter_chain = parse_chain(&ticket.minter_chain).ok()?;
+    (0..minted_tickets).find(|index| {
+        Ticket::create_ticket_id(
+            hub_chain,
+            application_id,
+            &ticket.event_id,
+            &ticket.seat,
+            &minter_chain,
+            &ticket.metadata_hash,
+            *index,
+        )
+        .is_ok_and(|ticket_id| ticket_id == ticket.ticket_id)
+    })
+}
+
+impl TryFrom<LegacyEvent> for Event {
+    type Error = MigrationError;
+
+    fn try_from(event: LegacyEvent) -> Result<Self, Self::Error> {
+        Ok(Event {
+            id: event.id,
+            organizer_chain: parse_chain(&event.organizer_chain)?,
+            name: event.name,
+            description: event.description,
+            venue: event.venue,
+            start_time: event.start_time,
+            royalty_bps: event.royalty_bps,
+            max_tickets: event.max_tickets,
+            minted_tickets: event.minted_tickets,
+            released_tickets: 0,
+            image_url: event.image_url,
+            end_time: event.end_time,
+            base_price: event.base_price,
+            sale_start: None,
+            sale_end: None,
+            resale_start: None,
+            resale_end: None,
+            max_per_holder: None,
+            max_purchases_per_holder: None,
+            transfer_lockout_secs: None,
+            transfer_policy: TransferPolicy::default(),
+            collectible: None,
+        })
+    }
+}
+
+impl TryFrom<LegacyTicket> for Ticket {
+    type Error = MigrationError;
+
+    fn try_from(ticket: LegacyTicket) -> Result<Self, Self::Error> {
+        Ok(Ticket {
+            ticket_id: ticket.ticket_id,
+            event_id: ticket.event_id,
+            event_name: ticket.event_name,
+            seat: ticket.seat,
+            organizer_chain: parse_chain(&ticket.organizer_chain)?,
+            owner_chain: parse_chain(&ticket.owner_chain)?,
+            owner: parse_owner(&ticket.owner)?,
+            minter_chain: parse_chain(&ticket.minter_chain)?,
+            royalty_bps: ticket.royalty_bps,
+            metadata_hash: ticket.metadata_hash,
+            last_sale_price: ticket.last_sale_price,
+            image_url: ticket.image_url,
+            transfer_policy: TransferPolicy::default(),
+            minted_at: ticket.minted_at,
+            status: TicketStatus::Valid,
+            redemption: None,
+            mint_index: ticket.mint_index,
+        })
+    }
+}
+
+impl TryFrom<LegacyListing> for Listing {
+    type Error = MigrationError;
+
+    fn try_from(listing: LegacyListing) -> Result<Self, Self::Error> {
+        Ok(Listing {
+            ticket_id: listing.ticket_id,
+            seller_chain: parse_chain(&listing.seller_chain)?,
+            seller: parse_owner(&listing.seller)?,
+            price: listing.price,
+            status: listing.status,
+        })
+    }
+}
+
+impl TryFrom<LegacyOwnershipRecord> for OwnershipRecord {
+    type Error = MigrationError;
+
+    fn try_from(record: LegacyOwnershipRecord) -> Result<Self, Self::Error> {
+        Ok(OwnershipRecord {
+            owner: parse_owner(&record.owner)?,
+            owner_chain: parse_chain(&record.owner_chain)?,
+            acquired_at: record.acquired_at,
+            price_paid: record.price_paid,
+            acquisition_type: record.acquisition_type,
+        })
+    }
+}
+
+impl TryFrom<LegacyTicketHistory> for TicketHistory {
+    type Error = MigrationError;
+
+    fn try_from(history: LegacyTicketHistory) -> Result<Self, Self::Error> {
+        Ok(TicketHistory {
+            ownership_history: history
+                .ownership_history
+                .into_iter()
+                .map(OwnershipRecord::try_from)
+                .collect::<Result<_, _>>()?,
+            price_history: history.price_history,
+            check_ins: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::linera_base_types::CryptoHash;
+
+    use super::*;
+
+    #[test]
+    fn parses_chains_and_owners_leniently() {
+        let chain = ChainId(CryptoHash::test_hash("fan"));
+        assert_eq!(parse_chain(&format!(" {} ", chain)), Ok(chain));
+        assert_eq!(parse_chain("fan"), Err(MigrationError::InvalidChain("fan".to_string())));
+
+        let owner = AccountOwner::from(CryptoHash::test_hash("alice"));
+        assert_eq!(parse_owner(&owner.to_string().to_uppercase()), Ok(owner));
+        assert_eq!(parse_owner("alice"), Err(MigrationError::InvalidOwner("alice".to_string())));
+    }
+
+    #[test]
+    fn recovers_the_mint_index_of_a_legacy_ticket() {
+        let hub_chain = ChainId(CryptoHash::test_hash("hub"));
+        let application_id = ApplicationId::new(CryptoHash::test_hash("ticketing"));
+        let event_id = EventId { value: "concert".to_string() };
+        let minter_chain = ChainId(CryptoHash::test_hash("organizer"));
+        let metadata_hash = DataBlobHash(CryptoHash::test_hash("metadata"));
+        // Minted as the eighth ticket, before the index was recorded on tickets
+        let ticket_id = Ticket::create_ticket_id(
+            &hub_chain,
+            &application_id,
+            &event_id,
+            "A-1",
+            &minter_chain,
+            &metadata_hash,
+            7,
+        )
+        .unwrap();
+        let ticket = LegacyTicket {
+            ticket_id,
+            event_id,
+            event_name: "Concert".to_string(),
+            seat: "A-1".to_string(),
+            organizer_chain: minter_chain.to_string(),
+            owner_chain: ChainId(CryptoHash::test_hash("fan")).to_string(),
+            owner: AccountOwner::from(CryptoHash::test_hash("alice")).to_string(),
+            minter_chain: minter_chain.to_string(),
+            royalty_bps: 500,
+            metadata_hash,
+            last_sale_price: None,
+            image_url: None,
+            minted_at: 1_700_000_000_000,
+            mint_index: 0,
+        };
+
+        assert_eq!(recover_mint_index(&ticket, &hub_chain, &application_id, 10), Some(7));
+        // The counter must have passed the index, and the IDs depend on the old deployment
+        assert_eq!(recover_mint_index(&ticket, &hub_chain, &application_id, 7), None);
+        let other_app = ApplicationId::new(CryptoHash::test_hash("other"));
+        assert_eq!(recover_mint_index(&ticket, &hub_chain, &other_app, 10), None);
+    }
+
+    #[test]
+    fn converts_a_legacy_ticket_keeping_its_id() {
+        let minter_chain = ChainId(CryptoHash::test_hash("organizer"));
+        let legacy = LegacyTicket {
+            ticket_id: TicketId { id: vec![3; 32] },
+            event_id: EventId { value: "concert".to_string() },
+            event_name: "Concert".to_string(),
+            seat: "A-1".to_string(),
+            organizer_chain: minter_chain.to_string(),
+            owner_chain: ChainId(CryptoHash::test_hash("fan")).to_string(),
+            owner: AccountOwner::from(CryptoHash::test_hash("alice")).to_string().to_uppercase(),
+            minter_chain: minter_chain.to_string(),
+            royalty_bps: 500,
+            metadata_hash: DataBlobHash(CryptoHash::test_hash("metadata")),
+            last_sale_price: None,
+            image_url: None,
+            minted_at: 1_700_000_000_000,
+            mint_index: 3,
+        };
+
+        let ticket = Ticket::try_from(legacy.clone()).unwrap();
+        assert_eq!(ticket.ticket_id, TicketId { id: vec![3; 32] });
+        assert_eq!(ticket.mint_index, 3);
+        assert_eq!(ticket.owner, AccountOwner::from(CryptoHash::test_hash("alice")));
+        assert_eq!(ticket.owner_chain, ChainId(CryptoHash::test_hash("fan")));
+
+        let broken = LegacyTicket { owner_chain: "not a chain".to_string(), ..legacy };
+        assert_eq!(
+            Ticket::try_from(broken),
+            Err(MigrationError::InvalidChain("not a chain".to_string()))
+        );
+    }
+}
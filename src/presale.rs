@@ -0,0 +1,228 @@
+// Copyright (c) Zefchain Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/*! Presale allowlists that gate primary mints during an event's presale window.
+ *
+ *  An allowlist names owner accounts and/or chains (`HolderKey`), either stored in full
+ *  on the hub or committed to as a Merkle root, with mints supplying a proof.
+ *  A chain entry only admits mints its owner signs themselves: a box office minting for
+ *  someone else is checked against the recipient account alone.
+ *
+ *  Merkle trees are built as follows:
+ *  - Leaves are `leaf_hash(key)`: SHA3-256 over 0x00 || BCS(key)
+ *  - Parents are SHA3-256 over 0x01 || min(a, b) || max(a, b), so proofs need no
+ *    left/right flags
+ *  - A node without a sibling moves up a level unchanged
+ *
+ *  Organizers build the tree off-chain with `merkle_root` and hand each fan the output
+ *  of `merkle_proof`.
+ */
+
+use std::collections::BTreeSet;
+
+use linera_sdk::linera_base_types::{AccountOwner, ChainId};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest as _, Sha3_256};
+
+use crate::{is_within_window, HolderKey};
+
+/// A node of a presale Merkle tree.
+pub type MerkleHash = [u8; 32];
+
+/// Owners and chains admitted to a presale.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Allowlist {
+    /// Every admitted holder, stored on the hub
+    Full(BTreeSet<HolderKey>),
+    /// Root of a Merkle tree over the admitted holders; mints supply a proof
+    MerkleRoot(MerkleHash),
+}
+
+impl Allowlist {
+    /// Returns true if the owner account or, when given, the owner's own chain is admitted.
+    /// `proof` is only used by Merkle allowlists and is checked against each key.
+    pub fn admits(&self, owner: &AccountOwner, owner_chain: Option<&ChainId>, proof: &[MerkleHash]) -> bool {
+        let mut keys = std::iter::once(HolderKey::Owner(*owner))
+            .chain(owner_chain.map(|chain| HolderKey::Chain(*chain)));
+        match self {
+            Allowlist::Full(entries) => keys.any(|key| entries.contains(&key)),
+            Allowlist::MerkleRoot(root) => keys.any(|key| verify_proof(root, leaf_hash(&key), proof)),
+        }
+    }
+}
+
+/// Presale of an event: while its window is open, only allowlisted holders can receive
+/// minted tickets, regardless of the event's regular sale window.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Presale {
+    /// Presale opens at this time (Unix seconds); None = open immediately
+    pub starts_at: Option<u64>,
+    /// Presale closes at this time (Unix seconds)
+    pub ends_at: u64,
+    pub allowlist: Allowlist,
+}
+
+impl Presale {
+    /// Returns true if the presale window is open at `now` (Unix seconds).
+    pub fn is_active(&self, now: u64) -> bool {
+        is_within_window(self.starts_at, Some(self.ends_at), now)
+    }
+}
+
+/// Hashes an allowlist entry into a Merkle leaf.
+pub fn leaf_hash(key: &HolderKey) -> MerkleHash {
+    let mut hasher = Sha3_256::new();
+    hasher.update([0u8]);
+    hasher.update(bcs::to_bytes(key).expect("holder keys serialize"));
+    hasher.finalize().into()
+}
+
+/// Hashes two sibling nodes into their parent.
+fn node_hash(a: &MerkleHash, b: &MerkleHash) -> MerkleHash {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut hasher = Sha3_256::new();
+    hasher.update([1u8]);
+    hasher.update(first);
+    hasher.update(second);
+    hasher.finalize().into()
+}
+
+/// Returns true if `proof` links `leaf` to `root`.
+pub fn verify_proof(root: &MerkleHash, leaf: MerkleHash, proof: &[MerkleHash]) -> bool {
+    proof.iter().fold(leaf, |node, sibling| node_hash(&node, sibling)) == *root
+}
+
+/// Root of the Merkle tree over `leaves` (in the given order); None for an empty list.
+pub fn merkle_root(leaves: &[MerkleHash]) -> Option<MerkleHash> {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.pop()
+}
+
+/// Proof for the leaf at `index` in the tree built by `merkle_root`.
+pub fn merkle_proof(leaves: &[MerkleHash], mut index: usize) -> Option<Vec<MerkleHash>> {
+    if index >= leaves.len() {
+        return None;
+    }
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+    Some(proof)
+}
+
+fn next_level(level: &[MerkleHash]) -> Vec<MerkleHash> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [a, b] => node_hash(a, b),
+            [single] => *single,
+            _ => unreachable!("chunks of two"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use linera_sdk::linera_base_types::CryptoHash;
+
+    use super::*;
+
+    #[test]
+    fn every_leaf_of_an_odd_sized_tree_verifies() {
+        for count in [3, 5, 7] {
+            let leaves: Vec<MerkleHash> = (0..count)
+                .map(|index| {
+                    let chain = ChainId(CryptoHash::test_hash(format!("fan-{}", index)));
+                    leaf_hash(&HolderKey::Chain(chain))
+                })
+                .collect();
+            let root = merkle_root(&leaves).unwrap();
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = merkle_proof(&leaves, index).unwrap();
+                assert!(verify_proof(&root, *leaf, &proof), "leaf {} of {}", index, count);
+            }
+        }
+    }
+
+    #[test]
+    fn single_leaf_tree_is_its_own_root() {
+        let leaf = leaf_hash(&HolderKey::Chain(ChainId(CryptoHash::test_hash("fan-0"))));
+        assert_eq!(merkle_root(&[leaf]), Some(leaf));
+        assert_eq!(merkle_proof(&[leaf], 0), Some(Vec::new()));
+        assert!(verify_proof(&leaf, leaf, &[]));
+    }
+
+    #[test]
+    fn empty_tree_and_out_of_range_leaf_have_nothing() {
+        let leaf = leaf_hash(&HolderKey::Chain(ChainId(CryptoHash::test_hash("fan-0"))));
+        assert_eq!(merkle_root(&[]), None);
+        assert_eq!(merkle_proof(&[leaf, leaf], 2), None);
+    }
+
+    #[test]
+    fn bad_proofs_are_rejected() {
+        let leaves = [
+            leaf_hash(&HolderKey::Chain(ChainId(CryptoHash::test_hash("fan-0")))),
+            leaf_hash(&HolderKey::Chain(ChainId(CryptoHash::test_hash("fan-1")))),
+            leaf_hash(&HolderKey::Chain(ChainId(CryptoHash::test_hash("fan-2")))),
+            leaf_hash(&HolderKey::Owner(AccountOwner::from(CryptoHash::test_hash("fan-3")))),
+            leaf_hash(&HolderKey::Owner(AccountOwner::from(CryptoHash::test_hash("fan-4")))),
+        ];
+        let root = merkle_root(&leaves).unwrap();
+        let proof = merkle_proof(&leaves, 1).unwrap();
+
+        // Someone else's leaf, a truncated proof and a tampered sibling
+        assert!(!verify_proof(&root, leaves[2], &proof));
+        assert!(!verify_proof(&root, leaves[1], &proof[..proof.len() - 1]));
+        let mut tampered = proof.clone();
+        tampered[0][0] ^= 1;
+        assert!(!verify_proof(&root, leaves[1], &tampered));
+        // A leaf outside the tree
+        let outsider = leaf_hash(&HolderKey::Chain(ChainId(CryptoHash::test_hash("outsider"))));
+        assert!(!verify_proof(&root, outsider, &proof));
+    }
+
+    #[test]
+    fn merkle_allowlist_admits_owner_or_own_chain() {
+        let alice = AccountOwner::from(CryptoHash::test_hash("alice"));
+        let bob = AccountOwner::from(CryptoHash::test_hash("bob"));
+        let fan_chain = ChainId(CryptoHash::test_hash("fan-chain"));
+        let leaves = [
+            leaf_hash(&HolderKey::Owner(alice)),
+            leaf_hash(&HolderKey::Chain(fan_chain)),
+            leaf_hash(&HolderKey::Owner(AccountOwner::from(CryptoHash::test_hash("carol")))),
+        ];
+        let allowlist = Allowlist::MerkleRoot(merkle_root(&leaves).unwrap());
+
+        let alice_proof = merkle_proof(&leaves, 0).unwrap();
+        assert!(allowlist.admits(&alice, None, &alice_proof));
+        assert!(!allowlist.admits(&bob, None, &alice_proof));
+
+        let chain_proof = merkle_proof(&leaves, 1).unwrap();
+        assert!(allowlist.admits(&bob, Some(&fan_chain), &chain_proof));
+        assert!(!allowlist.admits(&bob, None, &chain_proof));
+    }
+
+    #[test]
+    fn chain_entries_only_admit_mints_signed_by_the_owner() {
+        let box_office = ChainId(CryptoHash::test_hash("box-office"));
+        let fan = AccountOwner::from(CryptoHash::test_hash("fan"));
+        let allowlist = Allowlist::Full(BTreeSet::from([HolderKey::Chain(box_office)]));
+
+        // A box office minting for a fan is not admitted through its own chain entry
+        assert!(!allowlist.admits(&fan, None, &[]));
+        assert!(allowlist.admits(&fan, Some(&box_office), &[]));
+
+        let allowlist = Allowlist::Full(BTreeSet::from([HolderKey::Owner(fan)]));
+        assert!(allowlist.admits(&fan, None, &[]));
+    }
+}
@@ -13,11 +13,15 @@ use std::{
 use async_graphql::{EmptySubscription, Object, Request, Response, Schema};
 use base64::engine::{general_purpose::STANDARD_NO_PAD, Engine as _};
 use linera_sdk::{
-    linera_base_types::{DataBlobHash, WithServiceAbi},
+    linera_base_types::{AccountOwner, ChainId, CryptoHash, DataBlobHash, WithServiceAbi},
     views::View,
     Service, ServiceRuntime,
 };
-use ticketing::{EventId, Operation, TicketId, TicketOutput, TicketingAbi, TicketHistory};
+use ticketing::{
+    Allowlist, CheckIn, EventId, EventRole, FraudAlert, HolderKey, MerkleHash, Operation,
+    PendingWrite, Presale, RedeemedSnapshot, SeatRange, SyncCollection, TicketHistory, TicketId,
+    TicketOutput, TicketingAbi, TransferPolicy, WriteKind, WriteStatus,
+};
 use ticketing::ListingStatus;
 
 use self::state::TicketingState;
@@ -36,14 +40,99 @@ impl WithServiceAbi for TicketingService {
 #[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
 struct ListingInfo {
     ticket_id: String,
-    seller_chain: String,
-    seller: String,
+    seller_chain: ChainId,
+    seller: AccountOwner,
     price: String,
     status: String,
     // Wave 6: Add event name for search
     event_name: Option<String>,
 }
 
+/// A check-in recorded by a scanner device (scanned_at in Unix ms, as string)
+#[derive(async_graphql::InputObject)]
+struct CheckInInput {
+    ticket_id: String,
+    gate: String,
+    scanned_at: String,
+}
+
+/// Pre-show transfer lockout window of a ticket (Unix seconds, as strings)
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct TransferLockoutInfo {
+    locked_from: String,
+    transferable_at: String,
+    is_locked: bool,
+}
+
+/// Post-event collectible terms (blob hash as hex string)
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct CollectibleInfo {
+    image_url: Option<String>,
+    blob_hash: Option<String>,
+    royalty_bps: u16,
+    converted_at: String,
+}
+
+/// Presale of an event (times in Unix seconds, as strings).
+/// Full allowlists report their size; Merkle allowlists their root as hex.
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct PresaleInfo {
+    starts_at: Option<String>,
+    ends_at: String,
+    is_active: bool,
+    allowlist_size: Option<u32>,
+    merkle_root: Option<String>,
+}
+
+/// Progress of this chain's paginated sync from the hub.
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct SyncProgressInfo {
+    /// Collection of the page still expected from the hub (null when idle)
+    pending_collection: Option<SyncCollection>,
+    pages_received: u32,
+    completed: bool,
+}
+
+/// How far this chain trails the hub's marketplace stream (sequences as strings).
+/// `hub_sequence` is the highest hub sequence this chain has seen, so lag it has not
+/// heard of yet is not counted.
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct SyncLagInfo {
+    hub_sequence: String,
+    applied_sequence: String,
+    behind: String,
+    syncing: bool,
+}
+
+/// Hub outcome of a record written optimistically on this chain.
+/// `id` is the event ID, or the base64 ticket ID for tickets and listings.
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct WriteStatusInfo {
+    kind: WriteKind,
+    id: String,
+    status: WriteStatus,
+}
+
+/// Hub-published snapshot of redeemed tickets for offline verification.
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct RedeemedSnapshotInfo {
+    event_id: String,
+    taken_at: String,
+    /// Number of tickets of the event whose holder the snapshot records
+    holder_count: u32,
+    ticket_ids: Vec<String>,
+    bcs: String,
+}
+
+/// Per-event holder counts and what is left under the organizer's caps.
+#[derive(async_graphql::SimpleObject, Clone, serde::Serialize)]
+struct HolderAllowance {
+    held: u32,
+    purchased: u32,
+    remaining_holdings: Option<u32>,
+    remaining_purchases: Option<u32>,
+}
+
 impl Service for TicketingService {
     type Parameters = ();
 
@@ -64,6 +153,7 @@ impl Service for TicketingService {
                 runtime: self.runtime.clone(),
             },
             MutationRoot {
+                state: self.state.clone(),
                 runtime: self.runtime.clone(),
             },
             EmptySubscription,
@@ -81,20 +171,18 @@ struct QueryRoot {
 #[Object]
 impl QueryRoot {
     /// Get current chain ID (caller identity)
-    async fn chain_id(&self) -> String {
-        self.runtime.chain_id().to_string()
+    async fn chain_id(&self) -> ChainId {
+        self.runtime.chain_id()
     }
     
     /// Get the marketplace (hub) chain ID where shared data lives
-    async fn marketplace_chain(&self) -> String {
-        self.state.marketplace_chain.get().clone()
+    async fn marketplace_chain(&self) -> Option<ChainId> {
+        *self.state.marketplace_chain.get()
     }
     
     /// Check if current chain is the marketplace hub
     async fn is_hub(&self) -> bool {
-        let current = self.runtime.chain_id().to_string();
-        let marketplace = self.state.marketplace_chain.get().clone();
-        current == marketplace
+        *self.state.marketplace_chain.get() == Some(self.runtime.chain_id())
     }
 
     async fn event(&self, event_id: String) -> Option<ticketing::Event> {
@@ -105,6 +193,35 @@ impl QueryRoot {
             .unwrap()
     }
 
+    /// Whether primary sales are currently open for an event (false if unknown)
+    async fn is_on_sale(&self, event_id: String) -> bool {
+        let now = self.runtime.system_time().micros() / 1_000_000;
+        self.state
+            .events
+            .get(&EventId { value: event_id })
+            .await
+            .unwrap()
+            .is_some_and(|event| event.is_on_sale(now))
+    }
+
+    /// Events created by an organizer chain
+    async fn events_by_organizer(&self, organizer_chain: ChainId) -> Vec<ticketing::Event> {
+        let event_ids = self
+            .state
+            .events_by_organizer
+            .get(&organizer_chain)
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        let mut events = Vec::with_capacity(event_ids.len());
+        for event_id in event_ids {
+            if let Some(event) = self.state.events.get(&event_id).await.unwrap() {
+                events.push(event);
+            }
+        }
+        events
+    }
+
     async fn events(&self) -> BTreeMap<String, ticketing::Event> {
         let mut map = BTreeMap::new();
         self.state
@@ -120,29 +237,57 @@ impl QueryRoot {
 
     async fn ticket(&self, ticket_id: String) -> Option<TicketOutput> {
         let decoded = decode_ticket_id(&ticket_id);
-        let ticket = self.state.tickets.get(&decoded).await.unwrap();
-        ticket.map(|ticket| {
-            let payload = vec![];
-            TicketOutput::new(ticket, payload)
-        })
+        let ticket = self.state.tickets.get(&decoded).await.unwrap()?;
+        let event = self.state.events.get(&ticket.event_id).await.unwrap();
+        let now = self.runtime.system_time().micros() / 1_000_000;
+        let payload = self.read_metadata_blob(ticket.metadata_hash).await;
+        Some(TicketOutput::new(ticket, payload).with_event(event.as_ref(), now))
     }
 
     async fn tickets(&self) -> BTreeMap<String, TicketOutput> {
-        let mut tickets = BTreeMap::new();
+        // First collect events to tell expired tickets apart
+        let mut events = BTreeMap::new();
+        self.state
+            .events
+            .for_each_index_value(|event_id, event| {
+                events.insert(event_id, event.into_owned());
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let now = self.runtime.system_time().micros() / 1_000_000;
+
+        let mut stored = Vec::new();
         self.state
             .tickets
             .for_each_index_value(|_ticket_id, ticket| {
-                let ticket = ticket.into_owned();
-                let payload = vec![];
-                let output = TicketOutput::new(ticket, payload);
-                tickets.insert(output.ticket_id.clone(), output);
+                stored.push(ticket.into_owned());
                 Ok(())
             })
             .await
             .unwrap();
+        let mut tickets = BTreeMap::new();
+        for ticket in stored {
+            let event = events.get(&ticket.event_id);
+            let payload = self.read_metadata_blob(ticket.metadata_hash).await;
+            let output = TicketOutput::new(ticket, payload).with_event(event, now);
+            tickets.insert(output.ticket_id.clone(), output);
+        }
         tickets
     }
 
+    /// Collectible terms of an event, once its attended tickets were converted
+    async fn collectible_terms(&self, event_id: String) -> Option<CollectibleInfo> {
+        let event = self.state.events.get(&EventId { value: event_id }).await.unwrap()?;
+        let terms = event.collectible?;
+        Some(CollectibleInfo {
+            image_url: terms.image_url,
+            blob_hash: terms.blob_hash.map(|hash| hash.0.to_string()),
+            royalty_bps: terms.royalty_bps,
+            converted_at: terms.converted_at.to_string(),
+        })
+    }
+
     /// Get all active listings (filters out Cancelled/Sold)
     async fn listings(&self) -> BTreeMap<String, ListingInfo> {
         let mut map = BTreeMap::new();
@@ -186,6 +331,249 @@ impl QueryRoot {
         map
     }
 
+    /// Seats already minted for an event (normalized labels, sorted)
+    async fn taken_seats(&self, event_id: String) -> Vec<String> {
+        self.state
+            .taken_seats
+            .get(&EventId { value: event_id })
+            .await
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Check whether a seat can still be minted for an event
+    async fn is_seat_available(&self, event_id: String, seat: String) -> bool {
+        let Some(seat) = ticketing::normalize_seat(&seat) else {
+            // General admission is never reserved
+            return true;
+        };
+        self.state
+            .taken_seats
+            .get(&EventId { value: event_id })
+            .await
+            .unwrap()
+            .is_none_or(|seats| !seats.contains(&seat))
+    }
+
+    /// Delegated roles for an event, keyed by grantee chain (organizer holds all roles implicitly)
+    async fn event_roles(&self, event_id: String) -> BTreeMap<String, Vec<EventRole>> {
+        self.state
+            .event_roles
+            .get(&EventId { value: event_id })
+            .await
+            .unwrap()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(chain, roles)| (chain.to_string(), roles.into_iter().collect()))
+            .collect()
+    }
+
+    /// Remaining allowance of a holder (owner address on a chain) under the event's caps.
+    /// Remaining counts are null when the event sets no cap.
+    async fn holder_allowance(
+        &self,
+        event_id: String,
+        owner: AccountOwner,
+        owner_chain: ChainId,
+    ) -> Option<HolderAllowance> {
+        let event_id = EventId { value: event_id };
+        let event = self.state.events.get(&event_id).await.unwrap()?;
+        // Mirrors the contract: the hub chain and staff chains are only capped by owner address
+        let is_shared_chain = *self.state.marketplace_chain.get() == Some(owner_chain)
+            || event.organizer_chain == owner_chain
+            || self
+                .state
+                .event_roles
+                .get(&event_id)
+                .await
+                .unwrap()
+                .and_then(|mut roles| roles.remove(&owner_chain))
+                .is_some_and(|roles| ticketing::roles_grant(&roles, EventRole::Minter));
+        let [owner_key, chain_key] = ticketing::holder_keys(&owner, &owner_chain);
+        let keys = if is_shared_chain { vec![owner_key] } else { vec![owner_key, chain_key] };
+
+        let mut held = 0;
+        let mut purchased = 0;
+        for key in keys {
+            let key = (event_id.clone(), key);
+            held = held.max(self.state.holdings.get(&key).await.unwrap().unwrap_or(0));
+            purchased = purchased.max(self.state.purchases.get(&key).await.unwrap().unwrap_or(0));
+        }
+        Some(HolderAllowance {
+            held,
+            purchased,
+            remaining_holdings: event.max_per_holder.map(|max| max.saturating_sub(held)),
+            remaining_purchases: event
+                .max_purchases_per_holder
+                .map(|max| max.saturating_sub(purchased)),
+        })
+    }
+
+    /// Transfer lockout of a ticket's event, with when it becomes transferable again
+    /// (null if the ticket or its event is unknown, or the event has no lockout)
+    async fn ticket_transfer_lockout(&self, ticket_id: String) -> Option<TransferLockoutInfo> {
+        let ticket = self.state.tickets.get(&decode_ticket_id(&ticket_id)).await.unwrap()?;
+        let event = self.state.events.get(&ticket.event_id).await.unwrap()?;
+        let (locked_from, unlocks_at) = event.transfer_lockout()?;
+        let now = self.runtime.system_time().micros() / 1_000_000;
+        Some(TransferLockoutInfo {
+            locked_from: locked_from.to_string(),
+            transferable_at: unlocks_at.to_string(),
+            is_locked: event.transfer_locked_until(now).is_some(),
+        })
+    }
+
+    /// Presale window and allowlist summary of an event (null if it has none)
+    async fn presale(&self, event_id: String) -> Option<PresaleInfo> {
+        let presale = self.state.presales.get(&EventId { value: event_id }).await.unwrap()?;
+        let now = self.runtime.system_time().micros() / 1_000_000;
+        let (allowlist_size, merkle_root) = match &presale.allowlist {
+            Allowlist::Full(entries) => (Some(entries.len() as u32), None),
+            Allowlist::MerkleRoot(root) => (None, Some(CryptoHash::from(*root).to_string())),
+        };
+        Some(PresaleInfo {
+            starts_at: presale.starts_at.map(|t| t.to_string()),
+            ends_at: presale.ends_at.to_string(),
+            is_active: presale.is_active(now),
+            allowlist_size,
+            merkle_root,
+        })
+    }
+
+    /// Whether an owner may receive tickets during the event's presale.
+    /// Pass `owner_chain` only for mints the owner signs on their own chain; box office
+    /// mints for someone else are checked against the owner account alone.
+    /// Merkle allowlists need the holder's proof (hex hashes); false if there is no presale.
+    async fn is_presale_allowlisted(
+        &self,
+        event_id: String,
+        owner: AccountOwner,
+        owner_chain: Option<ChainId>,
+        proof: Option<Vec<String>>,
+    ) -> async_graphql::Result<bool> {
+        let proof = parse_merkle_proof(proof)?;
+        Ok(self
+            .state
+            .presales
+            .get(&EventId { value: event_id })
+            .await
+            .unwrap()
+            .is_some_and(|presale| presale.allowlist.admits(&owner, owner_chain.as_ref(), &proof)))
+    }
+
+    /// Progress of this chain's paginated sync from the hub
+    async fn sync_progress(&self) -> SyncProgressInfo {
+        let progress = self.state.sync_progress.get();
+        SyncProgressInfo {
+            pending_collection: progress.next.as_ref().map(|cursor| cursor.collection),
+            pages_received: progress.pages_received,
+            completed: progress.completed,
+        }
+    }
+
+    /// Stream events this chain has yet to apply, by hub sequence (zero on the hub)
+    async fn sync_lag(&self) -> SyncLagInfo {
+        let hub_sequence = *self.state.hub_sequence.get();
+        let is_hub = *self.state.marketplace_chain.get() == Some(self.runtime.chain_id());
+        let applied = if is_hub {
+            hub_sequence
+        } else {
+            (*self.state.applied_sequence.get()).max(*self.state.snapshot_sequence.get())
+        };
+        SyncLagInfo {
+            hub_sequence: hub_sequence.to_string(),
+            applied_sequence: applied.to_string(),
+            behind: hub_sequence.saturating_sub(applied).to_string(),
+            syncing: self.state.sync_progress.get().next.is_some(),
+        }
+    }
+
+    /// Hub outcome of an optimistic event, ticket or listing write made on this chain
+    async fn write_status(&self, kind: WriteKind, id: String) -> Option<WriteStatus> {
+        let write = match kind {
+            WriteKind::Event => PendingWrite::Event(EventId { value: id }),
+            WriteKind::Ticket => PendingWrite::Ticket(decode_ticket_id(&id)),
+            WriteKind::Listing => PendingWrite::Listing(decode_ticket_id(&id)),
+        };
+        self.state.write_status.get(&write).await.unwrap()
+    }
+
+    /// Optimistic writes made on this chain, optionally only those with the given status
+    async fn optimistic_writes(&self, status: Option<WriteStatus>) -> Vec<WriteStatusInfo> {
+        let mut writes = Vec::new();
+        self.state
+            .write_status
+            .for_each_index_value(|write, write_status| {
+                let write_status = *write_status;
+                if status.is_none_or(|status| status == write_status) {
+                    let id = match &write {
+                        PendingWrite::Event(event_id) => event_id.value.clone(),
+                        PendingWrite::Ticket(ticket_id) | PendingWrite::Listing(ticket_id) => {
+                            STANDARD_NO_PAD.encode(&ticket_id.id)
+                        }
+                    };
+                    writes.push(WriteStatusInfo { kind: write.kind(), id, status: write_status });
+                }
+                Ok(())
+            })
+            .await
+            .unwrap();
+        writes
+    }
+
+    /// Duplicate check-ins detected for an event
+    async fn fraud_alerts(&self, event_id: String) -> Vec<FraudAlert> {
+        self.state
+            .fraud_alerts
+            .get(&EventId { value: event_id })
+            .await
+            .unwrap()
+            .unwrap_or_default()
+    }
+
+    /// Ticket holders and redeemed tickets of an event, for loading into offline entry-pass
+    /// scanners. Published by the hub, whose ownership records the scanners trust.
+    /// `bcs` is the base64 BCS encoding of `ticketing::RedeemedSnapshot`.
+    async fn redeemed_snapshot(&self, event_id: String) -> RedeemedSnapshotInfo {
+        let event_id = EventId { value: event_id };
+        let mut holders = BTreeMap::new();
+        self.state
+            .tickets
+            .for_each_index_value(|ticket_id, ticket| {
+                if ticket.event_id == event_id {
+                    holders.insert(ticket_id, ticket.owner);
+                }
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let snapshot = RedeemedSnapshot {
+            event_id: event_id.clone(),
+            taken_at: self.runtime.system_time().micros() / 1000,
+            holders,
+            redeemed: self
+                .state
+                .redeemed_tickets
+                .get(&event_id)
+                .await
+                .unwrap()
+                .unwrap_or_default(),
+        };
+        RedeemedSnapshotInfo {
+            event_id: event_id.value,
+            taken_at: snapshot.taken_at.to_string(),
+            holder_count: snapshot.holders.len() as u32,
+            ticket_ids: snapshot
+                .redeemed
+                .iter()
+                .map(|ticket_id| STANDARD_NO_PAD.encode(&ticket_id.id))
+                .collect(),
+            bcs: STANDARD_NO_PAD.encode(bcs::to_bytes(&snapshot).expect("snapshot serializes")),
+        }
+    }
+
     /// Wave 6: Get ticket history (ownership and price history)
     async fn ticket_history(&self, ticket_id: String) -> Option<TicketHistory> {
         let decoded = decode_ticket_id(&ticket_id);
@@ -193,7 +581,7 @@ impl QueryRoot {
     }
 
     /// Get tickets owned by a specific chain
-    async fn owned_ticket_ids(&self, owner_chain: String) -> BTreeSet<String> {
+    async fn owned_ticket_ids(&self, owner_chain: ChainId) -> BTreeSet<String> {
         self.state
             .owned_ticket_ids
             .get(&owner_chain)
@@ -207,7 +595,7 @@ impl QueryRoot {
 
     /// Get my tickets (owned by current chain)
     async fn my_tickets(&self) -> BTreeSet<String> {
-        let my_chain = self.runtime.chain_id().to_string();
+        let my_chain = self.runtime.chain_id();
         self.state
             .owned_ticket_ids
             .get(&my_chain)
@@ -219,16 +607,15 @@ impl QueryRoot {
             .collect()
     }
 
-    /// Get tickets by owner wallet address (for demo mode)
-    async fn tickets_by_owner(&self, owner: String) -> Vec<String> {
-        let owner_lower = owner.to_lowercase();
+    /// Get tickets by owner account
+    async fn tickets_by_owner(&self, owner: AccountOwner) -> Vec<String> {
         let mut result = Vec::new();
         
         // Iterate through all tickets and filter by owner address
         let ticket_ids = self.state.tickets.indices().await.unwrap();
         for ticket_id in ticket_ids {
             if let Some(ticket) = self.state.tickets.get(&ticket_id).await.unwrap() {
-                if ticket.owner.to_lowercase() == owner_lower {
+                if ticket.owner == owner {
                     result.push(STANDARD_NO_PAD.encode(&ticket_id.id));
                 }
             }
@@ -237,8 +624,48 @@ impl QueryRoot {
         result
     }
 
+    /// Operator approved for a single ticket, if the approval was granted by its current owner
+    async fn ticket_approval(&self, ticket_id: String) -> Option<AccountOwner> {
+        let ticket_id = decode_ticket_id(&ticket_id);
+        let ticket = self.state.tickets.get(&ticket_id).await.unwrap()?;
+        self.state
+            .ticket_approvals
+            .get(&ticket_id)
+            .await
+            .unwrap()
+            .filter(|approval| approval.owner == ticket.owner)
+            .map(|approval| approval.operator)
+    }
+
+    /// Operators approved for all tickets an owner holds on a chain
+    async fn operators(&self, owner_chain: ChainId, owner: AccountOwner) -> Vec<AccountOwner> {
+        self.state
+            .operator_approvals
+            .get(&(owner_chain, owner))
+            .await
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    /// Whether an operator is approved for all tickets an owner holds on a chain
+    async fn is_approved_for_all(
+        &self,
+        owner_chain: ChainId,
+        owner: AccountOwner,
+        operator: AccountOwner,
+    ) -> bool {
+        self.state
+            .operator_approvals
+            .get(&(owner_chain, owner))
+            .await
+            .unwrap()
+            .is_some_and(|operators| operators.contains(&operator))
+    }
+
     /// Get royalty balance for a chain
-    async fn royalty_balance(&self, owner_chain: String) -> ticketing::BalanceEntryGraphQL {
+    async fn royalty_balance(&self, owner_chain: ChainId) -> ticketing::BalanceEntryGraphQL {
         let balance = self
             .state
             .royalty_balances
@@ -254,7 +681,7 @@ impl QueryRoot {
         self.state
             .royalty_balances
             .for_each_index_value(|owner_chain, balance| {
-                balances.insert(owner_chain.clone(), (&balance.into_owned()).into());
+                balances.insert(owner_chain.to_string(), (&balance.into_owned()).into());
                 Ok(())
             })
             .await
@@ -263,17 +690,37 @@ impl QueryRoot {
     }
 }
 
+impl QueryRoot {
+    /// Reads a ticket's metadata blob; None for the placeholder hash and for blobs this
+    /// chain has not seen verified, since reading an unpublished blob traps.
+    async fn read_metadata_blob(&self, hash: DataBlobHash) -> Option<Vec<u8>> {
+        if ticketing::is_placeholder_blob(&hash) {
+            return None;
+        }
+        if !self.state.verified_blobs.contains(&hash).await.unwrap() {
+            return None;
+        }
+        Some(self.runtime.read_data_blob(hash))
+    }
+}
+
 struct MutationRoot {
+    state: Arc<TicketingState>,
     runtime: Arc<ServiceRuntime<TicketingService>>,
 }
 
 #[Object]
 impl MutationRoot {
     /// Create a new event (caller becomes organizer)
+    /// event_id is an optional slug, scoped to the caller chain; omit it to get the next nonce
     /// Wave 6: Added optional image_url, end_time, base_price
+    /// Optional sale_start/sale_end (primary) and resale_start/resale_end (secondary) windows
+    /// Optional max_per_holder / max_purchases_per_holder caps per owner address or chain
+    /// Optional transfer_lockout_secs freezing transfers and resales before start_time
+    /// Optional transfer_policy (Free when omitted)
     async fn create_event(
         &self,
-        event_id: String,
+        event_id: Option<String>,
         name: String,
         description: String,
         venue: String,
@@ -283,46 +730,69 @@ impl MutationRoot {
         image_url: Option<String>,
         end_time: Option<i32>,
         base_price: Option<String>,
-    ) -> String {
+        sale_start: Option<i32>,
+        sale_end: Option<i32>,
+        resale_start: Option<i32>,
+        resale_end: Option<i32>,
+        max_per_holder: Option<i32>,
+        max_purchases_per_holder: Option<i32>,
+        transfer_lockout_secs: Option<i32>,
+        transfer_policy: Option<TransferPolicy>,
+    ) -> async_graphql::Result<String> {
+        // Predict the organizer-scoped ID the contract will assign
+        let organizer_chain = self.runtime.chain_id();
+        let slug = event_id.unwrap_or_default();
+        let nonce = self
+            .state
+            .event_nonces
+            .get(&organizer_chain)
+            .await
+            .unwrap()
+            .unwrap_or(0);
+        let events = &self.state.events;
+        let (event_id, _) = EventId::resolve(&organizer_chain, &slug, nonce, |candidate| async move {
+            events.contains_key(&candidate).await.unwrap()
+        })
+        .await;
         let operation = Operation::CreateEvent {
-            event_id: EventId { value: event_id.clone() },
+            event_id: EventId { value: slug },
             name,
             description,
             venue,
             start_time: start_time as u64,
-            royalty_bps: royalty_bps as u16,
+            royalty_bps: royalty_bps_input(royalty_bps)?,
             max_tickets: max_tickets as u32,
             image_url,
             end_time: end_time.map(|t| t as u64),
             base_price: base_price.and_then(|p| p.parse::<u128>().ok()),
+            sale_start: sale_start.map(|t| t as u64),
+            sale_end: sale_end.map(|t| t as u64),
+            resale_start: resale_start.map(|t| t as u64),
+            resale_end: resale_end.map(|t| t as u64),
+            max_per_holder: holder_limit(max_per_holder)?,
+            max_purchases_per_holder: holder_limit(max_purchases_per_holder)?,
+            transfer_lockout_secs: transfer_lockout_secs.map(|t| t as u64),
+            transfer_policy: transfer_policy.unwrap_or_default(),
         };
         self.runtime.schedule_operation(&operation);
-        format!("Event '{}' creation scheduled", event_id)
+        Ok(format!("Event '{}' creation scheduled", event_id.value))
     }
 
     /// Mint a ticket (caller must be event organizer)
-    /// owner: wallet address of the minter (for demo mode ownership tracking)
+    /// owner: wallet address of the minter (demo mode only; defaults to the block signer)
     /// Wave 6: Added optional image_url
+    /// Optional transfer_policy overrides the event default for this ticket (stricter only)
     async fn mint_ticket(
         &self,
         event_id: String,
         seat: String,
         blob_hash: String,
-        owner: String,
+        owner: Option<AccountOwner>,
         image_url: Option<String>,
-    ) -> String {
-        // Parse blob hash from hex string (simple manual hex decode)
-        let blob_hash_clean = blob_hash.strip_prefix("0x").unwrap_or(&blob_hash);
-        let hash_bytes: Vec<u8> = (0..blob_hash_clean.len())
-            .step_by(2)
-            .filter_map(|i| {
-                blob_hash_clean.get(i..i + 2)
-                    .and_then(|s| u8::from_str_radix(s, 16).ok())
-            })
-            .collect();
-        let hash_bytes = if hash_bytes.len() == 32 { hash_bytes } else { vec![0u8; 32] };
-        let hash_array: [u8; 32] = hash_bytes.try_into().unwrap_or([0u8; 32]);
-        let blob_hash = DataBlobHash(linera_sdk::linera_base_types::CryptoHash::from(hash_array));
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: Option<Vec<String>>,
+    ) -> async_graphql::Result<String> {
+        let blob_hash = parse_blob_hash(&blob_hash)?;
 
         let operation = Operation::MintTicket {
             event_id: EventId { value: event_id.clone() },
@@ -330,9 +800,264 @@ impl MutationRoot {
             blob_hash,
             owner,
             image_url,
+            transfer_policy,
+            presale_proof: parse_merkle_proof(presale_proof)?,
+        };
+        self.runtime.schedule_operation(&operation);
+        Ok(format!("Ticket for seat '{}' in event '{}' minting scheduled", seat, event_id))
+    }
+
+    /// Mint many seats at once from an explicit list and/or row ranges (caller must be organizer)
+    /// All tickets share the same metadata blob
+    async fn mint_ticket_batch(
+        &self,
+        event_id: String,
+        seats: Option<Vec<String>>,
+        ranges: Option<Vec<SeatRange>>,
+        blob_hash: String,
+        owner: Option<AccountOwner>,
+        image_url: Option<String>,
+        transfer_policy: Option<TransferPolicy>,
+        presale_proof: Option<Vec<String>>,
+    ) -> async_graphql::Result<String> {
+        let seats = seats.unwrap_or_default();
+        let ranges = ranges.unwrap_or_default();
+        let count = ticketing::batch_size(&seats, &ranges)
+            .ok_or_else(|| async_graphql::Error::new("seat range ends before it starts"))?;
+        if count > ticketing::MAX_MINT_BATCH as u64 {
+            return Err(async_graphql::Error::new(format!(
+                "batch exceeds {} seats",
+                ticketing::MAX_MINT_BATCH
+            )));
+        }
+        let operation = Operation::MintTicketBatch {
+            event_id: EventId { value: event_id.clone() },
+            seats,
+            ranges,
+            blob_hash: parse_blob_hash(&blob_hash)?,
+            owner,
+            image_url,
+            transfer_policy,
+            presale_proof: parse_merkle_proof(presale_proof)?,
+        };
+        self.runtime.schedule_operation(&operation);
+        Ok(format!("Batch of {} tickets in event '{}' minting scheduled", count, event_id))
+    }
+
+    /// Update per-holder caps of an event (caller must be organizer or event admin)
+    async fn set_holder_limits(
+        &self,
+        event_id: String,
+        max_per_holder: Option<i32>,
+        max_purchases_per_holder: Option<i32>,
+    ) -> async_graphql::Result<String> {
+        let operation = Operation::SetHolderLimits {
+            event_id: EventId { value: event_id.clone() },
+            max_per_holder: holder_limit(max_per_holder)?,
+            max_purchases_per_holder: holder_limit(max_purchases_per_holder)?,
+        };
+        self.runtime.schedule_operation(&operation);
+        Ok(format!("Holder limits update for event '{}' scheduled", event_id))
+    }
+
+    /// Set an event's presale (caller must be organizer or event admin)
+    /// Give either the allowed owners/chains or the hex root of a Merkle tree over them
+    /// (see `ticketing::presale`); starts_at/ends_at in Unix seconds
+    async fn set_presale(
+        &self,
+        event_id: String,
+        starts_at: Option<i32>,
+        ends_at: i32,
+        owners: Option<Vec<AccountOwner>>,
+        chains: Option<Vec<ChainId>>,
+        merkle_root: Option<String>,
+    ) -> async_graphql::Result<String> {
+        let allowlist = match merkle_root {
+            Some(root) => Allowlist::MerkleRoot(parse_hash_bytes(&root, "Merkle root")?),
+            None => Allowlist::Full(holder_keys_input(owners, chains).into_iter().collect()),
+        };
+        let operation = Operation::SetPresale {
+            event_id: EventId { value: event_id.clone() },
+            presale: Some(Presale {
+                starts_at: starts_at.map(|t| t as u64),
+                ends_at: ends_at as u64,
+                allowlist,
+            }),
+        };
+        self.runtime.schedule_operation(&operation);
+        Ok(format!("Presale for event '{}' scheduled", event_id))
+    }
+
+    /// Remove an event's presale (caller must be organizer or event admin)
+    async fn clear_presale(&self, event_id: String) -> String {
+        let operation = Operation::SetPresale {
+            event_id: EventId { value: event_id.clone() },
+            presale: None,
+        };
+        self.runtime.schedule_operation(&operation);
+        format!("Presale removal for event '{}' scheduled", event_id)
+    }
+
+    /// Add and remove owners/chains on an event's full presale allowlist
+    /// (caller must be organizer or event admin)
+    async fn update_presale_allowlist(
+        &self,
+        event_id: String,
+        add_owners: Option<Vec<AccountOwner>>,
+        add_chains: Option<Vec<ChainId>>,
+        remove_owners: Option<Vec<AccountOwner>>,
+        remove_chains: Option<Vec<ChainId>>,
+    ) -> String {
+        let operation = Operation::UpdatePresaleAllowlist {
+            event_id: EventId { value: event_id.clone() },
+            added: holder_keys_input(add_owners, add_chains),
+            removed: holder_keys_input(remove_owners, remove_chains),
         };
         self.runtime.schedule_operation(&operation);
-        format!("Ticket for seat '{}' in event '{}' minting scheduled", seat, event_id)
+        format!("Allowlist update for event '{}' scheduled", event_id)
+    }
+
+    /// Burn a ticket (caller must hold it, or be organizer/event admin giving a reason)
+    /// release_seat gives the seat and capacity back to the event (organizer/event admin only)
+    async fn burn_ticket(
+        &self,
+        ticket_id: String,
+        owner: Option<AccountOwner>,
+        reason: Option<String>,
+        release_seat: Option<bool>,
+    ) -> String {
+        let operation = Operation::BurnTicket {
+            ticket_id: decode_ticket_id(&ticket_id),
+            owner,
+            reason,
+            release_seat: release_seat.unwrap_or(false),
+        };
+        self.runtime.schedule_operation(&operation);
+        "Burn scheduled".to_string()
+    }
+
+    /// Approve an operator for one ticket (caller must hold it); omit operator to clear
+    async fn approve(
+        &self,
+        ticket_id: String,
+        operator: Option<AccountOwner>,
+        owner: Option<AccountOwner>,
+    ) -> String {
+        let operation = Operation::Approve {
+            ticket_id: decode_ticket_id(&ticket_id),
+            operator,
+            owner,
+        };
+        self.runtime.schedule_operation(&operation);
+        "Approval scheduled".to_string()
+    }
+
+    /// Approve or revoke an operator for all of the caller's tickets on this chain
+    async fn set_approval_for_all(
+        &self,
+        operator: AccountOwner,
+        approved: bool,
+        owner: Option<AccountOwner>,
+    ) -> String {
+        let operation = Operation::SetApprovalForAll {
+            operator,
+            approved,
+            owner,
+        };
+        self.runtime.schedule_operation(&operation);
+        format!("Operator {} for '{}' scheduled", if approved { "approval" } else { "revocation" }, operator)
+    }
+
+    /// Convert attended tickets of an ended event to collectibles (caller must be organizer or admin)
+    async fn convert_to_collectibles(
+        &self,
+        event_id: String,
+        image_url: Option<String>,
+        blob_hash: Option<String>,
+        royalty_bps: i32,
+    ) -> async_graphql::Result<String> {
+        let operation = Operation::ConvertToCollectibles {
+            event_id: EventId { value: event_id.clone() },
+            image_url,
+            blob_hash: blob_hash.map(|hash| parse_blob_hash(&hash)).transpose()?,
+            royalty_bps: royalty_bps_input(royalty_bps)?,
+        };
+        self.runtime.schedule_operation(&operation);
+        Ok(format!("Collectible conversion for event '{}' scheduled", event_id))
+    }
+
+    /// Check a ticket in at the venue (caller must be organizer or scanner for the event)
+    async fn redeem_ticket(&self, ticket_id: String, gate: String) -> String {
+        let operation = Operation::RedeemTicket {
+            ticket_id: decode_ticket_id(&ticket_id),
+            gate: gate.clone(),
+        };
+        self.runtime.schedule_operation(&operation);
+        format!("Redemption at gate '{}' scheduled", gate)
+    }
+
+    /// Submit check-ins recorded offline (caller must be organizer or scanner for the event)
+    async fn submit_check_ins(
+        &self,
+        event_id: String,
+        check_ins: Vec<CheckInInput>,
+    ) -> async_graphql::Result<String> {
+        let count = check_ins.len();
+        // A bogus time would win the "first scan" rule, so reject it instead of defaulting
+        let check_ins = check_ins
+            .into_iter()
+            .map(|check_in| {
+                let scanned_at = check_in.scanned_at.trim().parse::<u64>().map_err(|_| {
+                    async_graphql::Error::new(format!(
+                        "invalid scannedAt '{}' for ticket {}",
+                        check_in.scanned_at, check_in.ticket_id
+                    ))
+                })?;
+                Ok(CheckIn {
+                    ticket_id: decode_ticket_id(&check_in.ticket_id),
+                    gate: check_in.gate,
+                    scanned_at,
+                })
+            })
+            .collect::<async_graphql::Result<Vec<_>>>()?;
+        let operation = Operation::SubmitCheckIns {
+            event_id: EventId { value: event_id.clone() },
+            check_ins,
+        };
+        self.runtime.schedule_operation(&operation);
+        Ok(format!("{} check-ins for event '{}' submitted", count, event_id))
+    }
+
+    /// Update the pre-show transfer lockout of an event (caller must be organizer or event admin)
+    async fn set_transfer_lockout(&self, event_id: String, lockout_secs: Option<i32>) -> String {
+        let operation = Operation::SetTransferLockout {
+            event_id: EventId { value: event_id.clone() },
+            lockout_secs: lockout_secs.map(|t| t as u64),
+        };
+        self.runtime.schedule_operation(&operation);
+        format!("Transfer lockout update for event '{}' scheduled", event_id)
+    }
+
+    /// Grant a per-event role to a chain (caller must be organizer or event admin)
+    async fn grant_event_role(&self, event_id: String, chain: ChainId, role: EventRole) -> String {
+        let operation = Operation::GrantEventRole {
+            event_id: EventId { value: event_id.clone() },
+            chain,
+            role,
+        };
+        self.runtime.schedule_operation(&operation);
+        format!("Grant of {:?} to '{}' for event '{}' scheduled", role, chain, event_id)
+    }
+
+    /// Revoke a per-event role from a chain (caller must be organizer or event admin)
+    async fn revoke_event_role(&self, event_id: String, chain: ChainId, role: EventRole) -> String {
+        let operation = Operation::RevokeEventRole {
+            event_id: EventId { value: event_id.clone() },
+            chain,
+            role,
+        };
+        self.runtime.schedule_operation(&operation);
+        format!("Revocation of {:?} from '{}' for event '{}' scheduled", role, chain, event_id)
     }
 
     /// Transfer a ticket (caller must own it)
@@ -340,8 +1065,8 @@ impl MutationRoot {
     async fn transfer_ticket(
         &self,
         ticket_id: String,
-        buyer_chain: String,
-        new_owner: String,
+        buyer_chain: ChainId,
+        new_owner: AccountOwner,
         sale_price: Option<String>,
     ) -> String {
         let sale_price = sale_price.and_then(|s| s.parse::<u128>().ok());
@@ -356,12 +1081,12 @@ impl MutationRoot {
     }
 
     /// Create a listing (caller must own the ticket)
-    /// seller: wallet address of the seller (must match ticket.owner)
+    /// seller: wallet address of the seller (demo mode only; must match ticket.owner)
     async fn create_listing(
         &self,
         ticket_id: String,
         price: String,
-        seller: String,
+        seller: Option<AccountOwner>,
     ) -> String {
         let price = price.parse::<u128>().unwrap_or(0);
         let operation = Operation::CreateListing {
@@ -374,8 +1099,8 @@ impl MutationRoot {
     }
 
     /// Cancel a listing (caller must be seller)
-    /// seller: wallet address of the seller (must match listing.seller)
-    async fn cancel_listing(&self, ticket_id: String, seller: String) -> String {
+    /// seller: wallet address of the seller (demo mode only; must match listing.seller)
+    async fn cancel_listing(&self, ticket_id: String, seller: Option<AccountOwner>) -> String {
         let operation = Operation::CancelListing {
             ticket_id: decode_ticket_id(&ticket_id),
             seller,
@@ -385,8 +1110,13 @@ impl MutationRoot {
     }
 
     /// Buy a listing (caller becomes buyer)
-    /// buyer: wallet address of the buyer
-    async fn buy_listing(&self, ticket_id: String, price: String, buyer: String) -> String {
+    /// buyer: wallet address of the buyer (demo mode only; defaults to the block signer)
+    async fn buy_listing(
+        &self,
+        ticket_id: String,
+        price: String,
+        buyer: Option<AccountOwner>,
+    ) -> String {
         let price = price.parse::<u128>().unwrap_or(0);
         let operation = Operation::BuyListing {
             ticket_id: decode_ticket_id(&ticket_id),
@@ -397,14 +1127,16 @@ impl MutationRoot {
         "Purchase scheduled".to_string()
     }
 
-    /// Claim a ticket from a remote chain
+    /// Claim a ticket from a remote chain (caller must own it or be an approved operator)
     /// new_owner: wallet address of the new owner
+    /// owner: wallet address of the claimant (demo mode only; defaults to the block signer)
     async fn claim_ticket(
         &self,
-        source_chain: String,
+        source_chain: ChainId,
         ticket_id: String,
-        new_owner: String,
+        new_owner: AccountOwner,
         sale_price: Option<String>,
+        owner: Option<AccountOwner>,
     ) -> String {
         let sale_price = sale_price.and_then(|s| s.parse::<u128>().ok());
         let operation = Operation::ClaimTicket {
@@ -412,6 +1144,7 @@ impl MutationRoot {
             ticket_id: decode_ticket_id(&ticket_id),
             new_owner,
             sale_price,
+            owner,
         };
         self.runtime.schedule_operation(&operation);
         "Claim request sent".to_string()
@@ -424,6 +1157,68 @@ impl MutationRoot {
         self.runtime.schedule_operation(&operation);
         "Subscription to hub event stream scheduled".to_string()
     }
+
+    /// Re-request the pending page of an interrupted hub sync.
+    async fn resume_sync(&self) -> String {
+        let operation = Operation::ResumeSync;
+        self.runtime.schedule_operation(&operation);
+        "Sync resume scheduled".to_string()
+    }
+}
+
+/// Parses 32 bytes from a 64-digit hex string (optional `0x` prefix)
+fn parse_hash_bytes(hex: &str, what: &str) -> async_graphql::Result<[u8; 32]> {
+    let hex_clean = hex.strip_prefix("0x").unwrap_or(hex);
+    let invalid = || async_graphql::Error::new(format!("invalid {} '{}'", what, hex));
+    if hex_clean.len() != 64 || !hex_clean.is_ascii() {
+        return Err(invalid());
+    }
+    let hash_bytes = (0..hex_clean.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex_clean[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| invalid())?;
+    hash_bytes.try_into().map_err(|_| invalid())
+}
+
+/// Parses a blob hash from a 64-digit hex string (optional `0x` prefix)
+fn parse_blob_hash(blob_hash: &str) -> async_graphql::Result<DataBlobHash> {
+    let hash_array = parse_hash_bytes(blob_hash, "blob hash")?;
+    Ok(DataBlobHash(CryptoHash::from(hash_array)))
+}
+
+/// Parses a presale Merkle proof given as hex hashes (empty when omitted)
+fn parse_merkle_proof(proof: Option<Vec<String>>) -> async_graphql::Result<Vec<MerkleHash>> {
+    proof
+        .unwrap_or_default()
+        .iter()
+        .map(|hash| parse_hash_bytes(hash, "Merkle proof hash"))
+        .collect()
+}
+
+/// Combines owner and chain arguments into presale allowlist entries
+fn holder_keys_input(owners: Option<Vec<AccountOwner>>, chains: Option<Vec<ChainId>>) -> Vec<HolderKey> {
+    let owners = owners.unwrap_or_default().into_iter().map(HolderKey::Owner);
+    let chains = chains.unwrap_or_default().into_iter().map(HolderKey::Chain);
+    owners.chain(chains).collect()
+}
+
+/// Converts royalty basis points from GraphQL, rejecting values a u16 cannot hold
+/// (the contract checks the `MAX_BPS` bound).
+fn royalty_bps_input(royalty_bps: i32) -> async_graphql::Result<u16> {
+    u16::try_from(royalty_bps)
+        .map_err(|_| async_graphql::Error::new(format!("invalid royalty_bps {}", royalty_bps)))
+}
+
+/// Converts a per-holder cap from GraphQL, rejecting negative values.
+fn holder_limit(limit: Option<i32>) -> async_graphql::Result<Option<u32>> {
+    limit
+        .map(|limit| {
+            u32::try_from(limit).map_err(|_| {
+                async_graphql::Error::new(format!("holder limit must not be negative, got {}", limit))
+            })
+        })
+        .transpose()
 }
 
 fn decode_ticket_id(ticket_id: &str) -> TicketId {
@@ -1,16 +1,21 @@
 // Copyright (c) Zefchain Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use linera_sdk::{
-    views::{linera_views, MapView, RegisterView, RootView, ViewStorageContext},
+    linera_base_types::{AccountOwner, ChainId, DataBlobHash},
+    views::{linera_views, LogView, MapView, RegisterView, RootView, SetView, ViewStorageContext},
+};
+use ticketing::{
+    BalanceEntry, Event, EventId, EventRole, FraudAlert, HolderKey, PendingWrite, Presale,
+    SyncProgress, Ticket, TicketApproval, TicketHistory, TicketId, WriteStatus,
 };
-use ticketing::{BalanceEntry, Event, EventId, Ticket, TicketId, TicketHistory};
 
 /// All on-chain data required by the ticketing contract and service.
-/// Uses String (chain_id) as identity keys instead of AccountOwner.
-/// 
+/// Chains are keyed by `ChainId`; state written before chains were typed is brought over
+/// with `Operation::ImportLegacyState` (see `ticketing::migration`).
+///
 /// Hub-and-Spoke Model:
 /// - marketplace_chain: The hub chain ID where shared data lives
 /// - events/listings: Only populated on the hub chain
@@ -19,19 +24,64 @@ use ticketing::{BalanceEntry, Event, EventId, Ticket, TicketId, TicketHistory};
 #[view(context = ViewStorageContext)]
 pub struct TicketingState {
     /// The marketplace (hub) chain ID - set during instantiation
-    pub marketplace_chain: RegisterView<String>,
+    pub marketplace_chain: RegisterView<Option<ChainId>>,
     /// Events - primarily on hub, synced from user chains
     pub events: MapView<EventId, Event>,
     /// Tickets - on hub for reference, on user chains for ownership
     pub tickets: MapView<TicketId, Ticket>,
     /// Marketplace listings keyed by ticket id - only on hub
     pub listings: MapView<TicketId, ticketing::Listing>,
-    /// Tickets owned by each chain (keyed by chain_id string)
-    pub owned_ticket_ids: MapView<String, BTreeSet<TicketId>>,
-    /// Royalty balances keyed by chain_id string
-    pub royalty_balances: MapView<String, BalanceEntry>,
+    /// Tickets owned by each chain
+    pub owned_ticket_ids: MapView<ChainId, BTreeSet<TicketId>>,
+    /// Royalty balances per organizer chain
+    pub royalty_balances: MapView<ChainId, BalanceEntry>,
     pub total_royalties: RegisterView<u128>,
     // === Wave 6: Ticket History (Provenance) ===
     /// Ownership and price history for each ticket
     pub ticket_history: MapView<TicketId, TicketHistory>,
+    /// Reserved seats per event (normalized labels) - one ticket per seat
+    pub taken_seats: MapView<EventId, BTreeSet<String>>,
+    /// Delegated roles per event, keyed by grantee chain
+    pub event_roles: MapView<EventId, BTreeMap<ChainId, BTreeSet<EventRole>>>,
+    /// Redeemed tickets per event - published as the offline entry-pass snapshot
+    pub redeemed_tickets: MapView<EventId, BTreeSet<TicketId>>,
+    /// Duplicate check-ins detected per event
+    pub fraud_alerts: MapView<EventId, Vec<FraudAlert>>,
+    /// Events per organizer chain
+    pub events_by_organizer: MapView<ChainId, BTreeSet<EventId>>,
+    /// Next event ID nonce per organizer chain (only advanced on the organizer chain)
+    pub event_nonces: MapView<ChainId, u64>,
+    /// Tickets currently held per (event, holder key) - see `ticketing::holder_keys`
+    pub holdings: MapView<(EventId, HolderKey), u32>,
+    /// Tickets ever minted to or bought by each (event, holder key)
+    pub purchases: MapView<(EventId, HolderKey), u32>,
+    /// Presale window and allowlist per event
+    pub presales: MapView<EventId, Presale>,
+    /// Operator approved for a single ticket - cleared when the ticket is transferred
+    pub ticket_approvals: MapView<TicketId, TicketApproval>,
+    /// Operators approved for all tickets an owner holds on a chain, keyed by (owner chain, owner)
+    pub operator_approvals: MapView<(ChainId, AccountOwner), BTreeSet<AccountOwner>>,
+    /// Paginated sync from the hub - only on user chains
+    pub sync_progress: RegisterView<SyncProgress>,
+    /// Hub: sequence of the last emitted stream event.
+    /// User chains: highest hub sequence seen in the stream or in sync pages
+    pub hub_sequence: RegisterView<u64>,
+    /// Last hub stream sequence applied on this user chain
+    pub applied_sequence: RegisterView<u64>,
+    /// Hub sequence reflected by the last completed sync (see `SyncProgress::base_sequence`)
+    pub snapshot_sequence: RegisterView<u64>,
+    /// Hub sequence of the last stream event touching each event, ticket and listing,
+    /// so delta syncs can page over what changed
+    pub event_changes: MapView<EventId, u64>,
+    pub ticket_changes: MapView<TicketId, u64>,
+    pub listing_changes: MapView<TicketId, u64>,
+    /// The same changes in the order they happened, so sync pages walk on from their cursor
+    pub event_log: LogView<(u64, EventId)>,
+    pub ticket_log: LogView<(u64, TicketId)>,
+    pub listing_log: LogView<(u64, TicketId)>,
+    /// Hub outcome of the optimistic writes made on this user chain
+    pub write_status: MapView<PendingWrite, WriteStatus>,
+    /// Metadata blobs known to be published - checked by the hub, trusted from it elsewhere.
+    /// The service only reads these, since reading an unpublished blob traps
+    pub verified_blobs: SetView<DataBlobHash>,
 }
@@ -248,7 +248,7 @@ const TransactionHistory = () => {
             console.log('[TransactionHistory] Fetching for owner:', owner);
             // Get user's tickets
             const ticketsResult = await hubQuery(`
-                query GetTicketsByOwner($owner: String!) {
+                query GetTicketsByOwner($owner: AccountOwner!) {
                     ticketsByOwner(owner: $owner)
                 }
             `, { owner });
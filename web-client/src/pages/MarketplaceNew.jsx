@@ -1,6 +1,6 @@
 import React, { useState, useMemo, useEffect, useCallback } from 'react';
 import toast from 'react-hot-toast';
-import { useWallet } from '../contexts/WalletContext';
+import { useWallet, claimedOwner } from '../contexts/WalletContext';
 import { useLinera } from '../providers/LineraProvider';
 import { useDebounce } from '../hooks/useDebounce';
 import { ShoppingBag, Ticket, DollarSign, Wallet, Loader2, RefreshCw, User, Hash, XCircle, Search, ArrowUpDown, Filter } from 'lucide-react';
@@ -25,13 +25,13 @@ const GET_TICKET_QUERY = `
 `;
 
 const BUY_TICKET_MUTATION = `
-  mutation BuyListing($ticketId: String!, $buyer: String!, $price: String!) {
+  mutation BuyListing($ticketId: String!, $buyer: AccountOwner, $price: String!) {
     buyListing(ticketId: $ticketId, buyer: $buyer, price: $price)
   }
 `;
 
 const CANCEL_LISTING_MUTATION = `
-  mutation CancelListing($ticketId: String!, $seller: String!) {
+  mutation CancelListing($ticketId: String!, $seller: AccountOwner) {
     cancelListing(ticketId: $ticketId, seller: $seller)
   }
 `;
@@ -589,7 +589,7 @@ const ListingCard = ({ ticketId, listing, preloadedTicket, isConnected, openWall
         
         try {
             console.log('[ListingCard] Buying ticket via SDK...');
-            await mutate(BUY_TICKET_MUTATION, { ticketId, buyer: userAddress, price });
+            await mutate(BUY_TICKET_MUTATION, { ticketId, buyer: claimedOwner(userAddress), price });
             onBought();
         } catch (err) {
             console.error('[ListingCard] Buy failed:', err);
@@ -607,7 +607,7 @@ const ListingCard = ({ ticketId, listing, preloadedTicket, isConnected, openWall
         
         try {
             console.log('[ListingCard] Cancelling listing via SDK...');
-            await mutate(CANCEL_LISTING_MUTATION, { ticketId, seller: userAddress });
+            await mutate(CANCEL_LISTING_MUTATION, { ticketId, seller: claimedOwner(userAddress) });
             onBought();
         } catch (err) {
             console.error('[ListingCard] Cancel failed:', err);
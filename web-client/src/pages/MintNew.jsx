@@ -1,7 +1,7 @@
 import React, { useState, useEffect, useMemo, useCallback } from 'react';
 import { useNavigate, useSearchParams } from 'react-router-dom';
 import toast from 'react-hot-toast';
-import { useWallet } from '../contexts/WalletContext';
+import { useWallet, claimedOwner } from '../contexts/WalletContext';
 import { useLinera } from '../providers/LineraProvider';
 import { Ticket, Sparkles, Calendar, MapPin, Wallet, Loader2, ChevronDown, DollarSign, Users, ArrowLeft } from 'lucide-react';
 
@@ -25,7 +25,7 @@ const GET_EVENT_QUERY = `
 `;
 
 const MINT_TICKET_MUTATION = `
-  mutation MintTicket($eventId: String!, $owner: String!, $seat: String!, $blobHash: String!) {
+  mutation MintTicket($eventId: String!, $owner: AccountOwner, $seat: String!, $blobHash: String!) {
     mintTicket(eventId: $eventId, owner: $owner, seat: $seat, blobHash: $blobHash)
   }
 `;
@@ -326,7 +326,7 @@ const Mint = () => {
             // Execute mutation via SDK - signed locally, sent to validators
             await mutate(MINT_TICKET_MUTATION, {
                 eventId: selectedEventId,
-                owner: userAddress,
+                owner: claimedOwner(userAddress),
                 seat: seat || 'General Admission',
                 blobHash,
             });
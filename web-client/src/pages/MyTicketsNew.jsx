@@ -1,14 +1,14 @@
 import React, { useState, useMemo, useEffect, useCallback, useRef } from 'react';
 import { useNavigate, useLocation } from 'react-router-dom';
 import toast from 'react-hot-toast';
-import { useWallet } from '../contexts/WalletContext';
+import { useWallet, claimedOwner } from '../contexts/WalletContext';
 import { useLinera } from '../providers/LineraProvider';
 import { Ticket as TicketIcon, Send, DollarSign, Tag, X, Wallet, Loader2, RefreshCw, AlertTriangle, XCircle, History, Filter, Search } from 'lucide-react';
 import TicketHistory from '../components/TicketHistory';
 
 // GraphQL queries as plain strings for direct blockchain calls
 const GET_TICKETS_BY_OWNER_QUERY = `
-  query GetTicketsByOwner($owner: String!) {
+  query GetTicketsByOwner($owner: AccountOwner!) {
     ticketsByOwner(owner: $owner)
   }
 `;
@@ -32,19 +32,19 @@ const GET_TICKET_QUERY = `
 `;
 
 const TRANSFER_TICKET_MUTATION = `
-  mutation TransferTicket($ticketId: String!, $newOwner: String!, $buyerChain: String!, $salePrice: String) {
+  mutation TransferTicket($ticketId: String!, $newOwner: AccountOwner!, $buyerChain: ChainId!, $salePrice: String) {
     transferTicket(ticketId: $ticketId, newOwner: $newOwner, buyerChain: $buyerChain, salePrice: $salePrice)
   }
 `;
 
 const LIST_FOR_SALE_MUTATION = `
-  mutation CreateListing($ticketId: String!, $seller: String!, $price: String!) {
+  mutation CreateListing($ticketId: String!, $seller: AccountOwner, $price: String!) {
     createListing(ticketId: $ticketId, seller: $seller, price: $price)
   }
 `;
 
 const CANCEL_LISTING_MUTATION = `
-  mutation CancelListing($ticketId: String!, $seller: String!) {
+  mutation CancelListing($ticketId: String!, $seller: AccountOwner) {
     cancelListing(ticketId: $ticketId, seller: $seller)
   }
 `;
@@ -386,7 +386,7 @@ const MyTickets = () => {
     const handleCancelListing = async (ticketId) => {
         try {
             console.log('[MyTickets] Cancelling listing via SDK...');
-            await mutate(CANCEL_LISTING_MUTATION, { ticketId, seller: userAddress });
+            await mutate(CANCEL_LISTING_MUTATION, { ticketId, seller: claimedOwner(userAddress) });
             handleRefetch();
         } catch (err) {
             console.error('[MyTickets] Cancel listing failed:', err);
@@ -457,7 +457,7 @@ const MyTickets = () => {
             console.log('[MyTickets] Listing ticket via SDK...');
             await mutate(LIST_FOR_SALE_MUTATION, { 
                 ticketId: actionTicket, 
-                seller: userAddress, 
+                seller: claimedOwner(userAddress), 
                 price: listForm.price 
             });
             closeModal();